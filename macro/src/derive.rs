@@ -0,0 +1,256 @@
+use {
+    proc_macro2::TokenStream,
+    quote::quote,
+    syn::{parse_quote, Data, DeriveInput, Member, Type},
+};
+
+///The structures that can be derived, and which marker traits each one implies
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) enum Structure {
+    AddAbelianGroup,
+    Ring,
+    CommutativeRing,
+    IntegralDomain,
+    Field,
+}
+
+impl Structure {
+    fn markers(self) -> &'static [&'static str] {
+        match self {
+            Structure::AddAbelianGroup => &["AddAssociative", "AddCommutative"],
+            Structure::Ring => &[
+                "AddAssociative",
+                "AddCommutative",
+                "MulAssociative",
+                "Distributive",
+            ],
+            Structure::CommutativeRing => &[
+                "AddAssociative",
+                "AddCommutative",
+                "MulAssociative",
+                "MulCommutative",
+                "Distributive",
+            ],
+            Structure::IntegralDomain => &[
+                "AddAssociative",
+                "AddCommutative",
+                "MulAssociative",
+                "MulCommutative",
+                "Distributive",
+                "NoZeroDivisors",
+            ],
+            Structure::Field => &[
+                "AddAssociative",
+                "AddCommutative",
+                "MulAssociative",
+                "MulCommutative",
+                "Distributive",
+                "NoZeroDivisors",
+                "UniquelyFactorizable",
+            ],
+        }
+    }
+
+    fn multiplicative(self) -> bool {
+        self != Structure::AddAbelianGroup
+    }
+
+    fn no_zero_divisors(self) -> bool {
+        matches!(self, Structure::IntegralDomain | Structure::Field)
+    }
+}
+
+//Checks for `#[traitor(component_wise)]`
+fn component_wise(input: &DeriveInput) -> syn::Result<bool> {
+    let mut component_wise = false;
+    for attr in input.attrs.iter().filter(|a| a.path().is_ident("traitor")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("component_wise") {
+                component_wise = true;
+                Ok(())
+            } else {
+                Err(meta.error("unknown traitor attribute, expected `component_wise`"))
+            }
+        })?;
+    }
+    Ok(component_wise)
+}
+
+pub(crate) fn derive_structure(input: DeriveInput, structure: Structure) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    let component_wise = component_wise(&input)?;
+
+    //the fields, if we need to generate the operations from them
+    let fields: Vec<(Member, Type)> = if component_wise {
+        match &input.data {
+            Data::Struct(s) => s
+                .fields
+                .iter()
+                .enumerate()
+                .map(|(i, f)| {
+                    let member = match &f.ident {
+                        Some(ident) => Member::Named(ident.clone()),
+                        None => Member::Unnamed(i.into()),
+                    };
+                    (member, f.ty.clone())
+                })
+                .collect(),
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &input.ident,
+                    "component-wise operations can only be derived for structs",
+                ))
+            }
+        }
+    } else {
+        Vec::new()
+    };
+
+    if component_wise && structure.no_zero_divisors() && fields.len() > 1 {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "a product of more than one ring always has zero divisors, so it cannot be an integral domain or field",
+        ));
+    }
+
+    //each distinct field type, to put bounds on
+    let mut field_types: Vec<&Type> = Vec::new();
+    for (_, ty) in fields.iter() {
+        if !field_types.contains(&ty) {
+            field_types.push(ty);
+        }
+    }
+
+    let mut tokens = TokenStream::new();
+
+    //the marker traits, which for component-wise operations also need to hold for every field
+    for marker in structure.markers() {
+        let marker = format_ident!("{}", marker);
+        let mut generics = input.generics.clone();
+        {
+            let where_clause = generics.make_where_clause();
+            for ty in field_types.iter() {
+                where_clause
+                    .predicates
+                    .push(parse_quote!(#ty: ::traitor::algebra::#marker));
+            }
+        }
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+        tokens.extend(quote! {
+            impl #impl_generics ::traitor::algebra::#marker for #name #ty_generics #where_clause {}
+        });
+    }
+
+    if !component_wise {
+        return Ok(tokens);
+    }
+
+    let members: Vec<_> = fields.iter().map(|(m, _)| m).collect();
+
+    //emits an impl where every field type satisfies the given bound
+    let impl_with = |bound: &dyn Fn(&Type) -> syn::WherePredicate, body: TokenStream, tr: TokenStream| {
+        let mut generics = input.generics.clone();
+        {
+            let where_clause = generics.make_where_clause();
+            for ty in field_types.iter() {
+                where_clause.predicates.push(bound(ty));
+            }
+        }
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+        quote! {
+            impl #impl_generics #tr for #name #ty_generics #where_clause {
+                #body
+            }
+        }
+    };
+
+    let mut binary_ops = vec![("Add", "add"), ("Sub", "sub")];
+    if structure.multiplicative() {
+        binary_ops.push(("Mul", "mul"));
+    }
+    if structure == Structure::Field {
+        binary_ops.push(("Div", "div"));
+    }
+
+    for (op, f) in binary_ops {
+        let (op, f) = (format_ident!("{}", op), format_ident!("{}", f));
+        let (op_assign, f_assign) = (format_ident!("{}Assign", op), format_ident!("{}_assign", f));
+
+        tokens.extend(impl_with(
+            &|ty| parse_quote!(#ty: ::core::ops::#op<Output = #ty>),
+            quote! {
+                type Output = Self;
+                #[inline]
+                fn #f(self, rhs: Self) -> Self {
+                    Self { #(#members: ::core::ops::#op::#f(self.#members, rhs.#members)),* }
+                }
+            },
+            quote!(::core::ops::#op),
+        ));
+        tokens.extend(impl_with(
+            &|ty| parse_quote!(#ty: ::core::ops::#op_assign),
+            quote! {
+                #[inline]
+                fn #f_assign(&mut self, rhs: Self) {
+                    #(::core::ops::#op_assign::#f_assign(&mut self.#members, rhs.#members);)*
+                }
+            },
+            quote!(::core::ops::#op_assign),
+        ));
+    }
+
+    tokens.extend(impl_with(
+        &|ty| parse_quote!(#ty: ::core::ops::Neg<Output = #ty>),
+        quote! {
+            type Output = Self;
+            #[inline]
+            fn neg(self) -> Self {
+                Self { #(#members: ::core::ops::Neg::neg(self.#members)),* }
+            }
+        },
+        quote!(::core::ops::Neg),
+    ));
+    tokens.extend(impl_with(
+        &|ty| parse_quote!(#ty: ::traitor::algebra::Zero),
+        quote! {
+            #[inline]
+            fn zero() -> Self {
+                Self { #(#members: ::traitor::algebra::Zero::zero()),* }
+            }
+            #[inline]
+            fn is_zero(&self) -> bool {
+                true #(&& ::traitor::algebra::Zero::is_zero(&self.#members))*
+            }
+        },
+        quote!(::traitor::algebra::Zero),
+    ));
+
+    if structure.multiplicative() {
+        tokens.extend(impl_with(
+            &|ty| parse_quote!(#ty: ::traitor::algebra::One),
+            quote! {
+                #[inline]
+                fn one() -> Self {
+                    Self { #(#members: ::traitor::algebra::One::one()),* }
+                }
+            },
+            quote!(::traitor::algebra::One),
+        ));
+    }
+
+    if structure == Structure::Field {
+        tokens.extend(impl_with(
+            &|ty| parse_quote!(#ty: ::traitor::algebra::Inv<Output = #ty>),
+            quote! {
+                type Output = Self;
+                #[inline]
+                fn inv(self) -> Self {
+                    Self { #(#members: ::traitor::algebra::Inv::inv(self.#members)),* }
+                }
+            },
+            quote!(::traitor::algebra::Inv),
+        ));
+    }
+
+    Ok(tokens)
+}
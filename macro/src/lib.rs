@@ -2,17 +2,25 @@ extern crate proc_macro;
 #[macro_use]
 extern crate quote;
 
+mod derive;
+
 use {
+    derive::{derive_structure, Structure},
     proc_macro2::TokenStream,
     quote::quote,
-    std::path::Path,
-    syn::{
-        parse::Parse, parse_macro_input, parse_quote, parse_str, punctuated::Punctuated,
-        GenericArgument, ImplItemType, TraitBoundModifier, Type, TypePath,
-    },
+    syn::{parse::Parse, parse_macro_input, parse_quote, punctuated::Punctuated, TraitBoundModifier},
 };
 
-/// See crate documentation for more information.
+///
+/// Implements a trait for every type satisfying its supertraits.
+///
+/// `#[auto_gen_impl(Constraint)]` on `trait Foo<K>: Bounds where ... { ... }` moves the supertraits
+/// into a trait alias `Constraint<K>` with the same visibility, generics and where-clause, makes it
+/// the sole supertrait of `Foo<K>`, and adds a blanket impl of `Foo<K>` for every `Constraint<K>`.
+///
+/// Since the blanket impl is empty, every method, associated type, and constant of the trait
+/// must have a default.
+///
 #[proc_macro_attribute]
 pub fn auto_gen_impl(
     args: proc_macro::TokenStream,
@@ -25,177 +33,494 @@ pub fn auto_gen_impl(
 }
 
 fn auto_gen_impl2(args: TokenStream, input: TokenStream) -> syn::Result<proc_macro2::TokenStream> {
-    // Try to parse the token stream from the attribute to get a list of proxy
-    // types.
-    let constraint_name = syn::parse2::<syn::Path>(args)?;
+    // The name of the trait alias to generate for the supertraits
+    let constraint_name = syn::parse2::<syn::Ident>(args)?;
 
     let mut trait_def = syn::parse2::<syn::ItemTrait>(input)?;
 
+    // Since every type meeting the constraint gets an impl, there must be nothing left to implement
+    let mut errors: Option<syn::Error> = None;
+    for item in trait_def.items.iter() {
+        let missing = match item {
+            syn::TraitItem::Fn(f) if f.default.is_none() => Some(("method", &f.sig.ident)),
+            syn::TraitItem::Type(t) if t.default.is_none() => Some(("associated type", &t.ident)),
+            syn::TraitItem::Const(c) if c.default.is_none() => Some(("associated constant", &c.ident)),
+            _ => None,
+        };
+        if let Some((kind, ident)) = missing {
+            let e = syn::Error::new(
+                ident.span(),
+                format!(
+                    "auto_gen_impl cannot generate an impl with the required {} `{}`, consider giving it a default",
+                    kind, ident
+                ),
+            );
+            match errors.as_mut() {
+                Some(errors) => errors.combine(e),
+                None => errors = Some(e),
+            }
+        }
+    }
+    if let Some(errors) = errors {
+        return Err(errors);
+    }
+
+    if trait_def.supertraits.is_empty() {
+        return Err(syn::Error::new(
+            trait_def.ident.span(),
+            "auto_gen_impl needs at least one supertrait to use as the constraint",
+        ));
+    }
+
+    let vis = trait_def.vis.clone();
     let constraints = trait_def.supertraits.clone();
     let trait_name = trait_def.ident.clone();
+    let generics = trait_def.generics.clone();
+    let (_, ty_generics, where_clause) = generics.split_for_impl();
 
     let new_bound = syn::TypeParamBound::Trait(syn::TraitBound {
         paren_token: None,
         modifier: TraitBoundModifier::None,
         lifetimes: None,
-        path: constraint_name.clone(),
+        path: parse_quote!(#constraint_name #ty_generics),
     });
 
     trait_def.supertraits.clear();
-    trait_def.supertraits.push(new_bound);
+    trait_def.supertraits.push(new_bound.clone());
 
-    let impl_constraint = constraint_name.clone();
-    Ok(quote!(pub trait #constraint_name = #constraints;
+    // Pick a name for the implementing type that doesn't clash with the trait's own parameters
+    let mut self_name = format_ident!("A");
+    while generics.type_params().any(|p| p.ident == self_name) {
+        self_name = format_ident!("{}_", self_name);
+    }
+    let mut impl_generics = generics.clone();
+    let position = impl_generics.lifetimes().count();
+    impl_generics
+        .params
+        .insert(position, parse_quote!(#self_name: #new_bound));
+    let (impl_generics, _, _) = impl_generics.split_for_impl();
+
+    let params = &generics.params;
+    let alias_generics = if params.is_empty() {
+        quote!()
+    } else {
+        quote!(<#params>)
+    };
+
+    Ok(quote!(#vis trait #constraint_name #alias_generics = #constraints #where_clause;
         #trait_def
-        impl <A: #constraint_name> #trait_name for A {}
+        impl #impl_generics #trait_name #ty_generics for #self_name #where_clause {}
     ))
 }
 
+//The operators `traitor_ops!` knows how to forward
+const BINARY_OPS: [(&str, &str); 4] = [("Add", "add"), ("Sub", "sub"), ("Mul", "mul"), ("Div", "div")];
+const UNARY_OPS: [(&str, &str); 2] = [("Neg", "neg"), ("Inv", "inv")];
+
+//The names of every operator `traitor_ops!` accepts, including the assigning variants
+fn known_ops() -> Vec<String> {
+    BINARY_OPS
+        .iter()
+        .flat_map(|(op, _)| vec![op.to_string(), format!("{}Assign", op)])
+        .chain(UNARY_OPS.iter().map(|(op, _)| op.to_string()))
+        .collect()
+}
+
 struct OpsArgs {
+    ops: Vec<syn::Ident>,
     name: syn::Ident,
-    generic_constraints: Option<syn::AngleBracketedGenericArguments>,
-    generic_args: Option<syn::AngleBracketedGenericArguments>,
+    generics: syn::Generics,
 }
 
 impl Parse for OpsArgs {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
-        let name: syn::Ident = input.parse()?;
-        let generic_constraints: Option<syn::AngleBracketedGenericArguments> = input.parse().ok();
-
-        let generic_args = match generic_constraints.clone() {
-            Some(g) => {
-                let mut g = g.clone();
-                for arg in g.args.iter_mut() {
-                    let n_arg = match arg {
-                        GenericArgument::Constraint(ref c) => {
-                            let mut segments = Punctuated::new();
-                            segments.push(c.ident.clone().into());
-
-                            GenericArgument::Type(Type::Path(TypePath {
-                                qself: None,
-                                path: syn::Path {
-                                    leading_colon: None,
-                                    segments,
-                                },
-                            }))
-                        }
-                        ref x => (*x).clone(),
-                    };
-
-                    *arg = n_arg;
+        let ops = if input.peek(syn::token::Bracket) {
+            let content;
+            syn::bracketed!(content in input);
+            let ops = Punctuated::<syn::Ident, syn::Token![,]>::parse_terminated(&content)?;
+            let known = known_ops();
+            for op in ops.iter() {
+                if !known.contains(&op.to_string()) {
+                    return Err(syn::Error::new(
+                        op.span(),
+                        format!("unknown operator `{}`, expected one of: {}", op, known.join(", ")),
+                    ));
                 }
-
-                Some(g)
             }
-            _ => None,
+            ops.into_iter().collect()
+        } else {
+            known_ops()
+                .iter()
+                .map(|op| syn::Ident::new(op, proc_macro2::Span::call_site()))
+                .collect()
         };
 
+        let name: syn::Ident = input.parse()?;
+        let mut generics: syn::Generics = input.parse()?;
+        if input.peek(syn::Token![where]) {
+            generics.where_clause = Some(input.parse()?);
+        }
+        if !input.is_empty() {
+            return Err(input.error("unexpected tokens after the type to implement operators for"));
+        }
+
         Ok(OpsArgs {
+            ops,
             name,
-            generic_constraints,
-            generic_args,
+            generics,
         })
     }
 }
 
+///
+/// Implements the `std::ops` operators for a type by forwarding them to its `traitor::ops` impls.
+///
+/// Takes the name of the type followed by its generic parameters (with their bounds) and an
+/// optional where-clause, eg, `traitor_ops!(Wrapper<T: Clone> where T: Default)`. By default, this
+/// forwards `Add`, `Sub`, `Mul` and `Div` along with their `*Assign` variants, `Neg`, and `Inv`
+/// (as `num_traits::Inv`). A subset can be chosen by listing them first in brackets, eg,
+/// `traitor_ops!([Add, AddAssign, Neg] Wrapper<T>)`.
+///
+/// Each operator is also implemented on references, eg, `&a + &b`, whenever the corresponding
+/// `traitor::ops` trait is implemented for references.
+///
 #[proc_macro]
 pub fn traitor_ops(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(input as OpsArgs);
-    let name = input.name;
+    traitor_ops2(input).into()
+}
 
-    let generic_constraints = match input.generic_constraints {
-        Some(g) => {
-            quote! {#g}
-        }
-        _ => quote! {""},
+fn traitor_ops2(input: OpsArgs) -> TokenStream {
+    let OpsArgs {
+        ops,
+        name,
+        generics,
+    } = input;
+    let (_, ty_generics, _) = generics.split_for_impl();
+    let ty = quote!(#name #ty_generics);
+
+    //the same generics with an extra lifetime for the reference impls
+    let mut ref_generics = generics.clone();
+    ref_generics
+        .params
+        .insert(0, parse_quote!('__traitor));
+    let ref_ty = quote!(&'__traitor #ty);
+
+    let with_bound = |generics: &syn::Generics, bound: syn::WherePredicate| {
+        let mut generics = generics.clone();
+        generics.make_where_clause().predicates.push(bound);
+        generics
     };
 
-    let generic_args = match input.generic_args {
-        Some(g) => {
-            quote! {#g}
-        }
-        _ => quote! {""},
-    };
+    let mut tokens = TokenStream::new();
+    for op in ops.iter() {
+        let op_str = op.to_string();
 
-    println!("{} {}", name, generic_args);
+        if let Some((_, f)) = BINARY_OPS.iter().find(|(name, _)| *name == op_str) {
+            let f = format_ident!("{}", f);
 
-    let impl_type: Type =
-        syn::parse_str(&format!("{} {}", name, generic_args)).expect("paase ishu");
+            let g = with_bound(&generics, parse_quote!(#ty: ::traitor::ops::#op));
+            let (impl_generics, _, where_clause) = g.split_for_impl();
+            tokens.extend(quote! {
+                impl #impl_generics ::core::ops::#op for #ty #where_clause {
+                    type Output = <#ty as ::traitor::ops::#op>::Output;
 
-    quote! {
-        impl #generic_constraints std::ops::Add for #impl_type
+                    #[inline]
+                    fn #f(self, rhs: Self) -> Self::Output {
+                        <#ty as ::traitor::ops::#op>::#f(self, rhs)
+                    }
+                }
+            });
+
+            let g = with_bound(&ref_generics, parse_quote!(#ref_ty: ::traitor::ops::#op<#ref_ty>));
+            let (impl_generics, _, where_clause) = g.split_for_impl();
+            tokens.extend(quote! {
+                impl #impl_generics ::core::ops::#op<#ref_ty> for #ref_ty #where_clause {
+                    type Output = <#ref_ty as ::traitor::ops::#op<#ref_ty>>::Output;
+
+                    #[inline]
+                    fn #f(self, rhs: #ref_ty) -> Self::Output {
+                        <#ref_ty as ::traitor::ops::#op<#ref_ty>>::#f(self, rhs)
+                    }
+                }
+            });
+        } else if let Some((_, f)) = BINARY_OPS
+            .iter()
+            .find(|(name, _)| format!("{}Assign", name) == op_str)
         {
-            type Output = <Self as ::traitor::ops::Add>::Output;
-
-            fn add(self, rhs: Self) -> Self::Output {
-                <Self as ::traitor::ops::Add>::add(self, rhs)
+            let f = format_ident!("{}_assign", f);
+
+            let g = with_bound(&generics, parse_quote!(#ty: ::traitor::ops::#op));
+            let (impl_generics, _, where_clause) = g.split_for_impl();
+            tokens.extend(quote! {
+                impl #impl_generics ::core::ops::#op for #ty #where_clause {
+                    #[inline]
+                    fn #f(&mut self, rhs: Self) {
+                        <#ty as ::traitor::ops::#op>::#f(self, rhs)
+                    }
+                }
+            });
+
+            let g = with_bound(&ref_generics, parse_quote!(#ty: ::traitor::ops::#op<#ref_ty>));
+            let (impl_generics, _, where_clause) = g.split_for_impl();
+            tokens.extend(quote! {
+                impl #impl_generics ::core::ops::#op<#ref_ty> for #ty #where_clause {
+                    #[inline]
+                    fn #f(&mut self, rhs: #ref_ty) {
+                        <#ty as ::traitor::ops::#op<#ref_ty>>::#f(self, rhs)
+                    }
+                }
+            });
+        } else if let Some((_, f)) = UNARY_OPS.iter().find(|(name, _)| *name == op_str) {
+            let f = format_ident!("{}", f);
+
+            //there is no `Inv` in std, so we use the one from `num_traits` like the rest of the crate
+            let std_op = if op_str == "Inv" {
+                quote!(::traitor::algebra::Inv)
+            } else {
+                quote!(::core::ops::#op)
+            };
+
+            for &(g, t) in [(&generics, &ty), (&ref_generics, &ref_ty)].iter() {
+                let g = with_bound(g, parse_quote!(#t: ::traitor::ops::#op));
+                let (impl_generics, _, where_clause) = g.split_for_impl();
+                tokens.extend(quote! {
+                    impl #impl_generics #std_op for #t #where_clause {
+                        type Output = <#t as ::traitor::ops::#op>::Output;
+
+                        #[inline]
+                        fn #f(self) -> Self::Output {
+                            <#t as ::traitor::ops::#op>::#f(self)
+                        }
+                    }
+                });
             }
         }
+    }
 
-        impl #generic_constraints std::ops::Sub for #impl_type
-        {
-            type Output = <Self as ::traitor::ops::Sub>::Output;
+    tokens
+}
 
-            fn sub(self, rhs: Self) -> Self::Output {
-                <Self as ::traitor::ops::Sub>::sub(self. rhs)
+macro_rules! structure_derives {
+    ($($(#[$attr:meta])* $name:ident => $fn:ident;)*) => {
+        $(
+            $(#[$attr])*
+            #[proc_macro_derive($name, attributes(traitor))]
+            pub fn $fn(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+                let input = parse_macro_input!(input as syn::DeriveInput);
+                match derive_structure(input, Structure::$name) {
+                    Ok(tokens) => tokens.into(),
+                    Err(e) => e.into_compile_error().into(),
+                }
             }
-        }
+        )*
+    };
+}
 
-        impl #generic_constraints std::ops::Neg for #impl_type
-        {
-            type Output = <Self as ::traitor::ops::Neg>::Output;
+structure_derives! {
+    ///
+    /// Implements `AddAssociative` and `AddCommutative`.
+    ///
+    /// With `#[traitor(component_wise)]` on a struct, this also implements `Add`, `Sub`, `Neg`,
+    /// `Zero`, and the `*Assign` variants by applying each to every field. In that case, the
+    /// marker traits are only implemented when every field implements them too.
+    ///
+    AddAbelianGroup => derive_add_abelian_group;
+
+    ///
+    /// Implements the marker traits for a `Ring`: `AddAssociative`, `AddCommutative`,
+    /// `MulAssociative`, and `Distributive`.
+    ///
+    /// With `#[traitor(component_wise)]`, this also implements `Mul` and `One` component-wise
+    /// along with everything from `#[derive(AddAbelianGroup)]`.
+    ///
+    Ring => derive_ring;
+
+    ///
+    /// Implements the marker traits for a `Ring` along with `MulCommutative`
+    ///
+    /// Supports `#[traitor(component_wise)]` just like `#[derive(Ring)]`.
+    ///
+    CommutativeRing => derive_commutative_ring;
+
+    ///
+    /// Implements the marker traits for a `CommutativeRing` along with `NoZeroDivisors`
+    ///
+    /// `Divisibility` still has to be implemented separately. Since a product of rings always has
+    /// zero divisors, `#[traitor(component_wise)]` is only allowed on structs with a single field.
+    ///
+    IntegralDomain => derive_integral_domain;
+
+    ///
+    /// Implements the marker traits for a `Field`: those of an `IntegralDomain` along with
+    /// `UniquelyFactorizable`.
+    ///
+    /// With `#[traitor(component_wise)]` on a newtype, this also forwards `Div`, `DivAssign`
+    /// and `Inv` to the inner type.
+    ///
+    Field => derive_field;
+}
 
-            fn neg(self) -> Self::Output {
-                <Self as ::traitor::ops::Neg>::neg(self)
+#[test]
+fn do_gen_test() {
+    let args: proc_macro2::TokenStream = quote!(MyConstraint);
+    let input: proc_macro2::TokenStream = quote!(
+        pub trait MyTrait: C1 + C2<Inner: C3> {
+            fn some_operation(self) -> f64 {
+                0.0
+            }
+        }
+    );
+
+    let output = auto_gen_impl2(args, input).unwrap();
+    let desired = quote!(
+        pub trait MyConstraint = C1 + C2<Inner: C3>;
+        pub trait MyTrait: MyConstraint {
+            fn some_operation(self) -> f64 {
+                0.0
             }
         }
+        impl<A: MyConstraint> MyTrait for A {}
+    );
+
+    let out_str = format!("{}", output);
+    let expect_str = format!("{}", desired);
+
+    assert!(out_str == expect_str, "{} \n!=\n{}", out_str, expect_str);
+    println!("{}", output);
+}
 
-        impl #generic_constraints std::ops::Mul for #impl_type
+#[test]
+fn ops_gen_test() {
+    let args = syn::parse2::<OpsArgs>(quote!([Neg] Wrapper<T: Clone> where T: Default)).unwrap();
+    let output = traitor_ops2(args);
+    let desired = quote!(
+        impl<T: Clone> ::core::ops::Neg for Wrapper<T>
+        where
+            T: Default,
+            Wrapper<T>: ::traitor::ops::Neg
         {
-            type Output = <Self as ::traitor::ops::Mul>::Output;
+            type Output = <Wrapper<T> as ::traitor::ops::Neg>::Output;
 
-            fn mul(self, rhs: Self) -> Self::Output {
-                <Self as ::traitor::ops::Mul>::mul(self, rhs)
+            #[inline]
+            fn neg(self) -> Self::Output {
+                <Wrapper<T> as ::traitor::ops::Neg>::neg(self)
             }
         }
-
-        impl #generic_constraints std::ops::Div for #impl_type
+        impl<'__traitor, T: Clone> ::core::ops::Neg for &'__traitor Wrapper<T>
+        where
+            T: Default,
+            &'__traitor Wrapper<T>: ::traitor::ops::Neg
         {
-            type Output = <#name as ::traitor::ops::Div>::Output;
+            type Output = <&'__traitor Wrapper<T> as ::traitor::ops::Neg>::Output;
 
-            fn div(self, rhs: Self) -> Self::Output {
-                <#name as ::traitor::ops::Div>::div(self, rhs)
+            #[inline]
+            fn neg(self) -> Self::Output {
+                <&'__traitor Wrapper<T> as ::traitor::ops::Neg>::neg(self)
             }
         }
-    }
-    .into()
+    );
+
+    let out_str = format!("{}", output);
+    let expect_str = format!("{}", desired);
+
+    assert!(out_str == expect_str, "{} \n!=\n{}", out_str, expect_str);
 }
 
 #[test]
-fn do_gen_test() {
+fn ops_error_test() {
+    let err = syn::parse2::<OpsArgs>(quote!([Add, Rem] Wrapper)).err().unwrap();
+    assert!(err.to_string().starts_with("unknown operator `Rem`"));
+
+    let err = syn::parse2::<OpsArgs>(quote!(Wrapper<T> T)).err().unwrap();
+    assert!(err.to_string().starts_with("unexpected tokens"));
+}
+
+#[test]
+fn derive_test() {
+    let input = syn::parse2(quote!(
+        #[traitor(component_wise)]
+        struct Pair<T>(T, T);
+    ))
+    .unwrap();
+    let output = derive_structure(input, Structure::Ring).unwrap().to_string();
+    assert!(output.contains(
+        &quote!(impl<T> ::traitor::algebra::Distributive for Pair<T> where T: ::traitor::algebra::Distributive {})
+            .to_string()
+    ));
+    assert!(output.contains(&quote!(impl<T> ::traitor::algebra::One for Pair<T>).to_string()));
+
+    let input = syn::parse2(quote!(
+        #[traitor(component_wise)]
+        struct Pair<T>(T, T);
+    ))
+    .unwrap();
+    let err = derive_structure(input, Structure::Field).err().unwrap();
+    assert!(err.to_string().contains("zero divisors"));
+
+    let input = syn::parse2(quote!(
+        #[traitor(componentwise)]
+        struct Wrapper(f64);
+    ))
+    .unwrap();
+    let err = derive_structure(input, Structure::Field).err().unwrap();
+    assert!(err.to_string().starts_with("unknown traitor attribute"));
+}
+
+#[test]
+fn gen_generics_test() {
     let args: proc_macro2::TokenStream = quote!(MyConstraint);
     let input: proc_macro2::TokenStream = quote!(
-        pub trait MyTrait: C1 + C2<Inner: C3> {
-            fn some_operation(self) -> f64 {
-                0.0
+        pub(crate) trait MyTrait<'a, A: C1, const N: usize>: C2<A> + 'a
+        where
+            A: C3,
+        {
+            type Output = A;
+            fn some_operation(&'a self) -> [A; N] {
+                unimplemented!()
             }
         }
     );
 
     let output = auto_gen_impl2(args, input).unwrap();
     let desired = quote!(
-        pub trait MyConstraint = C1 + C2<Inner: C3>;
-        pub trait MyTrait: MyConstraint {
-            fn some_operation(self) -> f64 {
-                0.0
+        pub(crate) trait MyConstraint<'a, A: C1, const N: usize> = C2<A> + 'a
+        where
+            A: C3,;
+        pub(crate) trait MyTrait<'a, A: C1, const N: usize>: MyConstraint<'a, A, N>
+        where
+            A: C3,
+        {
+            type Output = A;
+            fn some_operation(&'a self) -> [A; N] {
+                unimplemented!()
             }
         }
-        impl<A: MyConstraint> MyTrait for A {}
+        impl<'a, A_: MyConstraint<'a, A, N>, A: C1, const N: usize> MyTrait<'a, A, N> for A_
+        where
+            A: C3,
+        {}
     );
 
     let out_str = format!("{}", output);
     let expect_str = format!("{}", desired);
 
     assert!(out_str == expect_str, "{} \n!=\n{}", out_str, expect_str);
-    println!("{}", output);
+}
+
+#[test]
+fn gen_required_test() {
+    let input: proc_macro2::TokenStream = quote!(
+        pub trait MyTrait: C1 {
+            type Item;
+            fn provided(&self) {}
+            fn required(&self);
+        }
+    );
+
+    let err = auto_gen_impl2(quote!(MyConstraint), input).unwrap_err();
+    let messages: Vec<_> = err.into_iter().map(|e| e.to_string()).collect();
+    assert_eq!(messages.len(), 2);
+    assert!(messages[0].contains("associated type `Item`"));
+    assert!(messages[1].contains("method `required`"));
 }
@@ -0,0 +1,1307 @@
+//!
+//!Arbitrary-precision [Natural] and [Integer] numbers
+//!
+//![BigNat] stores its value as little-endian 64-bit limbs and [BigInt] pairs one with a sign, so
+//!unlike the primitive integers, neither can overflow. Both implement [IntegerSubset] and can be
+//!used anywhere a primitive is, including [TrialDivision], [baillie_psw] and [repeated_squaring].
+//!
+
+use {
+    crate::{
+        algebra::{integer::MulMod, *},
+        analysis::ordered::*,
+    },
+    core::{
+        cmp::Ordering,
+        convert::TryFrom,
+        fmt::{Debug, Display, Formatter, Result as FmtResult, Write},
+        ops::{Rem, RemAssign, Shl, Shr},
+        str::FromStr,
+    },
+    num_traits::{float::FloatCore, FromPrimitive, ToPrimitive},
+};
+
+///
+///An arbitrary-precision natural number
+///
+///The limbs are kept without any trailing zeros, so zero has no limbs at all and the derived
+///equality and hashing are exact.
+///
+///Like the primitive unsigned integers, subtracting a larger number panics.
+///
+#[derive(Clone, PartialEq, Eq, Hash, Default)]
+pub struct BigNat {
+    limbs: Vec<u64>,
+}
+
+///
+///An arbitrary-precision integer
+///
+///Stored as a sign and a [BigNat] magnitude where zero is never negative. Division with `/` and `%`
+///truncates towards zero just like the primitive integers, while [ArchimedeanDiv] gives the
+///Euclidean quotient and non-negative remainder.
+///
+#[derive(Clone, PartialEq, Eq, Hash, Default)]
+pub struct BigInt {
+    negative: bool,
+    mag: BigNat,
+}
+
+///The error returned when a value is out of range for the integer type being converted into
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct TryFromBigIntError;
+
+///The error returned when a string is not a valid decimal integer
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct ParseBigIntError;
+
+impl Display for TryFromBigIntError {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "out of range integral type conversion attempted")
+    }
+}
+
+impl Display for ParseBigIntError {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "invalid digit found in string")
+    }
+}
+
+//
+//Limb arithmetic
+//
+//Everything here works on little-endian slices of limbs without trailing zeros
+//
+
+const DECIMAL_CHUNK: u64 = 10_000_000_000_000_000_000;
+const DECIMAL_CHUNK_DIGITS: usize = 19;
+
+#[inline]
+fn trim(limbs: &mut Vec<u64>) {
+    while limbs.last() == Some(&0) {
+        limbs.pop();
+    }
+}
+
+#[inline]
+fn cmp_limbs(a: &[u64], b: &[u64]) -> Ordering {
+    a.len()
+        .cmp(&b.len())
+        .then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+fn add_limbs(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let (a, b) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let mut sum = Vec::with_capacity(a.len() + 1);
+    let mut carry = false;
+    for (i, &x) in a.iter().enumerate() {
+        let (s, c1) = x.overflowing_add(b.get(i).copied().unwrap_or(0));
+        let (s, c2) = s.overflowing_add(carry as u64);
+        sum.push(s);
+        carry = c1 || c2;
+    }
+    if carry {
+        sum.push(1);
+    }
+    sum
+}
+
+//assumes that `a >= b`
+fn sub_limbs(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let mut diff = Vec::with_capacity(a.len());
+    let mut borrow = false;
+    for (i, &x) in a.iter().enumerate() {
+        let (d, b1) = x.overflowing_sub(b.get(i).copied().unwrap_or(0));
+        let (d, b2) = d.overflowing_sub(borrow as u64);
+        diff.push(d);
+        borrow = b1 || b2;
+    }
+    trim(&mut diff);
+    diff
+}
+
+fn mul_limbs(a: &[u64], b: &[u64]) -> Vec<u64> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let mut prod = vec![0u64; a.len() + b.len()];
+    for (i, &x) in a.iter().enumerate() {
+        let mut carry = 0u128;
+        for (j, &y) in b.iter().enumerate() {
+            //(2^64-1)^2 + 2*(2^64-1) = 2^128-1, so this can never overflow
+            let t = x as u128 * y as u128 + prod[i + j] as u128 + carry;
+            prod[i + j] = t as u64;
+            carry = t >> 64;
+        }
+        prod[i + b.len()] = carry as u64;
+    }
+    trim(&mut prod);
+    prod
+}
+
+//multiplies by `m` and adds `a` in place
+fn mul_add_limb(limbs: &mut Vec<u64>, m: u64, a: u64) {
+    let mut carry = a as u128;
+    for x in limbs.iter_mut() {
+        let t = *x as u128 * m as u128 + carry;
+        *x = t as u64;
+        carry = t >> 64;
+    }
+    if carry != 0 {
+        limbs.push(carry as u64);
+    }
+}
+
+fn div_rem_limb(a: &[u64], d: u64) -> (Vec<u64>, u64) {
+    let mut q = vec![0u64; a.len()];
+    let mut r = 0u128;
+    for i in (0..a.len()).rev() {
+        let t = (r << 64) | a[i] as u128;
+        q[i] = (t / d as u128) as u64;
+        r = t % d as u128;
+    }
+    trim(&mut q);
+    (q, r as u64)
+}
+
+//shifts left by less than a limb, always leaving room for the overflow in an extra limb
+fn shl_bits(a: &[u64], s: u32) -> Vec<u64> {
+    let mut out = Vec::with_capacity(a.len() + 1);
+    let mut carry = 0;
+    for &x in a {
+        out.push((x << s) | carry);
+        carry = if s == 0 { 0 } else { x >> (64 - s) };
+    }
+    out.push(carry);
+    out
+}
+
+//shifts right by less than a limb
+fn shr_bits(a: &[u64], s: u32) -> Vec<u64> {
+    let mut out = Vec::with_capacity(a.len());
+    for (i, &x) in a.iter().enumerate() {
+        let hi = match a.get(i + 1) {
+            Some(&y) if s > 0 => y << (64 - s),
+            _ => 0,
+        };
+        out.push((x >> s) | hi);
+    }
+    trim(&mut out);
+    out
+}
+
+///
+///Long division using Knuth's Algorithm D
+///
+///Each quotient limb is estimated from the leading limbs of the remainder and the normalized
+///divisor, and that estimate is at most one too large after the usual correction step.
+///
+fn div_rem_limbs(a: &[u64], b: &[u64]) -> (Vec<u64>, Vec<u64>) {
+    if b.is_empty() {
+        panic!("Attempted to divide by zero")
+    }
+    if cmp_limbs(a, b) == Ordering::Less {
+        return (Vec::new(), a.to_vec());
+    }
+    if b.len() == 1 {
+        let (q, r) = div_rem_limb(a, b[0]);
+        let mut r = vec![r];
+        trim(&mut r);
+        return (q, r);
+    }
+
+    //normalize so that the leading limb of the divisor has its top bit set
+    let shift = b[b.len() - 1].leading_zeros();
+    let v = shl_bits(b, shift);
+    let n = b.len();
+    let v = &v[..n];
+    let mut u = shl_bits(a, shift);
+    let m = u.len() - n;
+    let mut q = vec![0u64; m];
+
+    const BASE: u128 = 1 << 64;
+    for j in (0..m).rev() {
+        let top = ((u[j + n] as u128) << 64) | u[j + n - 1] as u128;
+        let mut qhat = top / v[n - 1] as u128;
+        let mut rhat = top % v[n - 1] as u128;
+        while qhat >= BASE || qhat * v[n - 2] as u128 > ((rhat << 64) | u[j + n - 2] as u128) {
+            qhat -= 1;
+            rhat += v[n - 1] as u128;
+            if rhat >= BASE {
+                break;
+            }
+        }
+
+        //subtract qhat*v from the current window of u
+        let mut borrow = 0i128;
+        let mut carry = 0u128;
+        for i in 0..n {
+            let p = qhat * v[i] as u128 + carry;
+            carry = p >> 64;
+            let t = u[i + j] as i128 - (p as u64) as i128 + borrow;
+            u[i + j] = t as u64;
+            borrow = t >> 64;
+        }
+        let t = u[j + n] as i128 - carry as i128 + borrow;
+        u[j + n] = t as u64;
+
+        //if we went negative, the estimate was one too large, so add a copy of v back
+        if t < 0 {
+            qhat -= 1;
+            let mut carry = 0u128;
+            for i in 0..n {
+                let s = u[i + j] as u128 + v[i] as u128 + carry;
+                u[i + j] = s as u64;
+                carry = s >> 64;
+            }
+            u[j + n] = u[j + n].wrapping_add(carry as u64);
+        }
+        q[j] = qhat as u64;
+    }
+
+    trim(&mut q);
+    (q, shr_bits(&u[..n], shift))
+}
+
+fn to_decimal(limbs: &[u64]) -> String {
+    if limbs.is_empty() {
+        return String::from("0");
+    }
+    let mut chunks = Vec::new();
+    let mut rest = limbs.to_vec();
+    while !rest.is_empty() {
+        let (q, r) = div_rem_limb(&rest, DECIMAL_CHUNK);
+        chunks.push(r);
+        rest = q;
+    }
+
+    let mut s = String::with_capacity(chunks.len() * DECIMAL_CHUNK_DIGITS);
+    let mut chunks = chunks.into_iter().rev();
+    if let Some(first) = chunks.next() {
+        let _ = write!(s, "{}", first);
+    }
+    for chunk in chunks {
+        let _ = write!(s, "{:019}", chunk);
+    }
+    s
+}
+
+#[inline]
+fn nat_from_u128(x: u128) -> BigNat {
+    BigNat::from_limbs(vec![x as u64, (x >> 64) as u64])
+}
+
+impl BigNat {
+    ///Constructs a natural number from little-endian 64-bit limbs
+    #[inline]
+    pub fn from_limbs(limbs: Vec<u64>) -> Self {
+        let mut limbs = limbs;
+        trim(&mut limbs);
+        BigNat { limbs }
+    }
+
+    ///The little-endian 64-bit limbs of this number, without any trailing zeros
+    #[inline]
+    pub fn limbs(&self) -> &[u64] {
+        &self.limbs
+    }
+
+    ///The number of bits needed to represent this number, ie, zero for zero and `⌊log₂(self)⌋+1` otherwise
+    #[inline]
+    pub fn bits(&self) -> u64 {
+        match self.limbs.last() {
+            Some(top) => 64 * self.limbs.len() as u64 - top.leading_zeros() as u64,
+            None => 0,
+        }
+    }
+
+    ///Divides by `rhs` with remainder without consuming either operand
+    ///
+    ///Panics if `rhs` is zero
+    #[inline]
+    pub fn div_rem(&self, rhs: &Self) -> (Self, Self) {
+        let (q, r) = div_rem_limbs(&self.limbs, &rhs.limbs);
+        (BigNat { limbs: q }, BigNat { limbs: r })
+    }
+}
+
+impl BigInt {
+    ///Constructs an integer from its sign and magnitude, normalizing the sign of zero
+    #[inline]
+    pub fn from_sign_magnitude(negative: bool, magnitude: BigNat) -> Self {
+        BigInt {
+            negative: negative && !magnitude.is_zero(),
+            mag: magnitude,
+        }
+    }
+
+    ///The absolute value of this integer
+    #[inline]
+    pub fn magnitude(&self) -> &BigNat {
+        &self.mag
+    }
+
+    ///Splits this integer into its sign and magnitude
+    #[inline]
+    pub fn into_sign_magnitude(self) -> (bool, BigNat) {
+        (self.negative, self.mag)
+    }
+
+    ///
+    ///Divides by `rhs` with remainder without consuming either operand
+    ///
+    ///Like the primitive integers, the quotient is truncated towards zero and the remainder has
+    ///the sign of `self`. Panics if `rhs` is zero.
+    ///
+    #[inline]
+    pub fn div_rem(&self, rhs: &Self) -> (Self, Self) {
+        let (q, r) = self.mag.div_rem(&rhs.mag);
+        (
+            BigInt::from_sign_magnitude(self.negative != rhs.negative, q),
+            BigInt::from_sign_magnitude(self.negative, r),
+        )
+    }
+}
+
+//
+//Conversions
+//
+
+macro_rules! impl_from_prim {
+    (@unsigned $($t:ty)*) => {$(
+        impl From<$t> for BigNat {
+            #[inline] fn from(x: $t) -> Self { nat_from_u128(x as u128) }
+        }
+        impl From<$t> for BigInt {
+            #[inline] fn from(x: $t) -> Self { BigInt::from(BigNat::from(x)) }
+        }
+    )*};
+    (@signed $($t:ty)*) => {$(
+        impl TryFrom<$t> for BigNat {
+            type Error = TryFromBigIntError;
+            #[inline] fn try_from(x: $t) -> Result<Self, TryFromBigIntError> {
+                if x < 0 { Err(TryFromBigIntError) } else { Ok(nat_from_u128(x as u128)) }
+            }
+        }
+        impl From<$t> for BigInt {
+            #[inline] fn from(x: $t) -> Self {
+                BigInt::from_sign_magnitude(x < 0, nat_from_u128((x as i128).unsigned_abs()))
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_try_into_prim {
+    ($($t:ident:$to:ident)*) => {$(
+        impl TryFrom<BigNat> for $t {
+            type Error = TryFromBigIntError;
+            #[inline] fn try_from(x: BigNat) -> Result<Self, TryFromBigIntError> {
+                x.$to().ok_or(TryFromBigIntError)
+            }
+        }
+        impl TryFrom<BigInt> for $t {
+            type Error = TryFromBigIntError;
+            #[inline] fn try_from(x: BigInt) -> Result<Self, TryFromBigIntError> {
+                x.$to().ok_or(TryFromBigIntError)
+            }
+        }
+    )*};
+}
+
+impl_from_prim!(@unsigned u8 u16 u32 u64 u128 usize);
+impl_from_prim!(@signed i8 i16 i32 i64 i128 isize);
+impl_try_into_prim!(
+    u8:to_u8 u16:to_u16 u32:to_u32 u64:to_u64 u128:to_u128 usize:to_usize
+    i8:to_i8 i16:to_i16 i32:to_i32 i64:to_i64 i128:to_i128 isize:to_isize
+);
+
+impl From<BigNat> for BigInt {
+    #[inline]
+    fn from(x: BigNat) -> Self {
+        BigInt::from_sign_magnitude(false, x)
+    }
+}
+
+impl TryFrom<BigInt> for BigNat {
+    type Error = TryFromBigIntError;
+    #[inline]
+    fn try_from(x: BigInt) -> Result<Self, TryFromBigIntError> {
+        if x.negative {
+            Err(TryFromBigIntError)
+        } else {
+            Ok(x.mag)
+        }
+    }
+}
+
+impl ToPrimitive for BigNat {
+    #[inline]
+    fn to_i64(&self) -> Option<i64> {
+        self.to_u64().and_then(|x| i64::try_from(x).ok())
+    }
+    #[inline]
+    fn to_i128(&self) -> Option<i128> {
+        self.to_u128().and_then(|x| i128::try_from(x).ok())
+    }
+    #[inline]
+    fn to_u64(&self) -> Option<u64> {
+        match self.limbs.len() {
+            0 => Some(0),
+            1 => Some(self.limbs[0]),
+            _ => None,
+        }
+    }
+    #[inline]
+    fn to_u128(&self) -> Option<u128> {
+        match self.limbs.len() {
+            0 => Some(0),
+            1 => Some(self.limbs[0] as u128),
+            2 => Some(((self.limbs[1] as u128) << 64) | self.limbs[0] as u128),
+            _ => None,
+        }
+    }
+
+    ///Rounds towards zero to 64 significant bits before converting, overflowing to infinity
+    fn to_f64(&self) -> Option<f64> {
+        let bits = self.bits();
+        if bits <= 64 {
+            self.to_u64().map(|x| x as f64)
+        } else {
+            let shift = bits - 64;
+            let top = (self.clone() >> shift as usize).limbs[0] as f64;
+            Some(top * 2f64.powi(shift.min(i32::MAX as u64) as i32))
+        }
+    }
+}
+
+impl ToPrimitive for BigInt {
+    #[inline]
+    fn to_i64(&self) -> Option<i64> {
+        self.to_i128().and_then(|x| i64::try_from(x).ok())
+    }
+    #[inline]
+    fn to_i128(&self) -> Option<i128> {
+        let m = self.mag.to_u128()?;
+        if self.negative {
+            if m <= 1 << 127 {
+                Some((m as i128).wrapping_neg())
+            } else {
+                None
+            }
+        } else {
+            i128::try_from(m).ok()
+        }
+    }
+    #[inline]
+    fn to_u64(&self) -> Option<u64> {
+        if self.negative {
+            None
+        } else {
+            self.mag.to_u64()
+        }
+    }
+    #[inline]
+    fn to_u128(&self) -> Option<u128> {
+        if self.negative {
+            None
+        } else {
+            self.mag.to_u128()
+        }
+    }
+    #[inline]
+    fn to_f64(&self) -> Option<f64> {
+        let m = self.mag.to_f64()?;
+        Some(if self.negative { -m } else { m })
+    }
+}
+
+impl FromPrimitive for BigNat {
+    #[inline]
+    fn from_i64(n: i64) -> Option<Self> {
+        BigNat::try_from(n).ok()
+    }
+    #[inline]
+    fn from_i128(n: i128) -> Option<Self> {
+        BigNat::try_from(n).ok()
+    }
+    #[inline]
+    fn from_u64(n: u64) -> Option<Self> {
+        Some(BigNat::from(n))
+    }
+    #[inline]
+    fn from_u128(n: u128) -> Option<Self> {
+        Some(BigNat::from(n))
+    }
+
+    ///Truncates towards zero, failing for non-finite or negative values
+    fn from_f64(n: f64) -> Option<Self> {
+        if !n.is_finite() || n <= -1.0 {
+            return None;
+        }
+        let (mantissa, exponent, _) = FloatCore::integer_decode(n.trunc());
+        let mantissa = BigNat::from(mantissa);
+        Some(if exponent >= 0 {
+            mantissa << exponent as usize
+        } else {
+            mantissa >> (-exponent) as usize
+        })
+    }
+}
+
+impl FromPrimitive for BigInt {
+    #[inline]
+    fn from_i64(n: i64) -> Option<Self> {
+        Some(BigInt::from(n))
+    }
+    #[inline]
+    fn from_i128(n: i128) -> Option<Self> {
+        Some(BigInt::from(n))
+    }
+    #[inline]
+    fn from_u64(n: u64) -> Option<Self> {
+        Some(BigInt::from(n))
+    }
+    #[inline]
+    fn from_u128(n: u128) -> Option<Self> {
+        Some(BigInt::from(n))
+    }
+
+    ///Truncates towards zero, failing for non-finite values
+    #[inline]
+    fn from_f64(n: f64) -> Option<Self> {
+        BigNat::from_f64(n.abs()).map(|m| BigInt::from_sign_magnitude(n < 0.0, m))
+    }
+}
+
+//
+//Formatting and parsing
+//
+
+impl Display for BigNat {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        f.pad_integral(true, "", &to_decimal(&self.limbs))
+    }
+}
+
+impl Display for BigInt {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        f.pad_integral(!self.negative, "", &to_decimal(&self.mag.limbs))
+    }
+}
+
+impl Debug for BigNat {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        Display::fmt(self, f)
+    }
+}
+
+impl Debug for BigInt {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        Display::fmt(self, f)
+    }
+}
+
+impl FromStr for BigNat {
+    type Err = ParseBigIntError;
+
+    ///Parses a string of decimal digits with an optional leading `+`
+    fn from_str(s: &str) -> Result<Self, ParseBigIntError> {
+        let digits = s.strip_prefix('+').unwrap_or(s).as_bytes();
+        if digits.is_empty() || !digits.iter().all(u8::is_ascii_digit) {
+            return Err(ParseBigIntError);
+        }
+
+        let mut limbs = Vec::new();
+        for chunk in digits.chunks(DECIMAL_CHUNK_DIGITS) {
+            let value = chunk
+                .iter()
+                .fold(0u64, |acc, d| acc * 10 + (d - b'0') as u64);
+            mul_add_limb(&mut limbs, 10u64.pow(chunk.len() as u32), value);
+        }
+        Ok(BigNat::from_limbs(limbs))
+    }
+}
+
+impl FromStr for BigInt {
+    type Err = ParseBigIntError;
+
+    ///Parses a string of decimal digits with an optional leading `+` or `-`
+    fn from_str(s: &str) -> Result<Self, ParseBigIntError> {
+        match s.strip_prefix('-') {
+            Some(rest) if !rest.starts_with('+') => {
+                BigNat::from_str(rest).map(|m| BigInt::from_sign_magnitude(true, m))
+            }
+            Some(_) => Err(ParseBigIntError),
+            None => BigNat::from_str(s).map(BigInt::from),
+        }
+    }
+}
+
+//
+//Unary operations
+//
+
+impl Neg for BigInt {
+    type Output = BigInt;
+    #[inline]
+    fn neg(self) -> BigInt {
+        BigInt::from_sign_magnitude(!self.negative, self.mag)
+    }
+}
+
+impl Neg for &BigInt {
+    type Output = BigInt;
+    #[inline]
+    fn neg(self) -> BigInt {
+        -self.clone()
+    }
+}
+
+//
+//Binary operations
+//
+//Each operation is implemented between references and forwarded to for owned operands
+//
+
+macro_rules! forward_binops {
+    ($t:ident; $($Op:ident.$op:ident $OpAssign:ident.$op_assign:ident)*) => {$(
+        impl $Op<$t> for $t {
+            type Output = $t;
+            #[inline] fn $op(self, rhs: $t) -> $t { (&self).$op(&rhs) }
+        }
+        impl<'a> $Op<&'a $t> for $t {
+            type Output = $t;
+            #[inline] fn $op(self, rhs: &'a $t) -> $t { (&self).$op(rhs) }
+        }
+        impl<'a> $Op<$t> for &'a $t {
+            type Output = $t;
+            #[inline] fn $op(self, rhs: $t) -> $t { self.$op(&rhs) }
+        }
+        impl $OpAssign<$t> for $t {
+            #[inline] fn $op_assign(&mut self, rhs: $t) { *self = (&*self).$op(&rhs) }
+        }
+        impl<'a> $OpAssign<&'a $t> for $t {
+            #[inline] fn $op_assign(&mut self, rhs: &'a $t) { *self = (&*self).$op(rhs) }
+        }
+    )*};
+}
+
+forward_binops!(BigNat; Add.add AddAssign.add_assign Sub.sub SubAssign.sub_assign
+    Mul.mul MulAssign.mul_assign Div.div DivAssign.div_assign Rem.rem RemAssign.rem_assign);
+forward_binops!(BigInt; Add.add AddAssign.add_assign Sub.sub SubAssign.sub_assign
+    Mul.mul MulAssign.mul_assign Div.div DivAssign.div_assign Rem.rem RemAssign.rem_assign);
+
+impl Add<&BigNat> for &BigNat {
+    type Output = BigNat;
+    #[inline]
+    fn add(self, rhs: &BigNat) -> BigNat {
+        BigNat {
+            limbs: add_limbs(&self.limbs, &rhs.limbs),
+        }
+    }
+}
+
+impl Sub<&BigNat> for &BigNat {
+    type Output = BigNat;
+    #[inline]
+    fn sub(self, rhs: &BigNat) -> BigNat {
+        if cmp_limbs(&self.limbs, &rhs.limbs) == Ordering::Less {
+            panic!("Attempted to subtract a larger BigNat from a smaller one")
+        }
+        BigNat {
+            limbs: sub_limbs(&self.limbs, &rhs.limbs),
+        }
+    }
+}
+
+impl Mul<&BigNat> for &BigNat {
+    type Output = BigNat;
+    #[inline]
+    fn mul(self, rhs: &BigNat) -> BigNat {
+        BigNat {
+            limbs: mul_limbs(&self.limbs, &rhs.limbs),
+        }
+    }
+}
+
+impl Div<&BigNat> for &BigNat {
+    type Output = BigNat;
+    #[inline]
+    fn div(self, rhs: &BigNat) -> BigNat {
+        self.div_rem(rhs).0
+    }
+}
+
+impl Rem<&BigNat> for &BigNat {
+    type Output = BigNat;
+    #[inline]
+    fn rem(self, rhs: &BigNat) -> BigNat {
+        self.div_rem(rhs).1
+    }
+}
+
+impl Add<&BigInt> for &BigInt {
+    type Output = BigInt;
+    fn add(self, rhs: &BigInt) -> BigInt {
+        if self.negative == rhs.negative {
+            BigInt::from_sign_magnitude(self.negative, &self.mag + &rhs.mag)
+        } else {
+            match cmp_limbs(&self.mag.limbs, &rhs.mag.limbs) {
+                Ordering::Less => BigInt::from_sign_magnitude(rhs.negative, &rhs.mag - &self.mag),
+                _ => BigInt::from_sign_magnitude(self.negative, &self.mag - &rhs.mag),
+            }
+        }
+    }
+}
+
+impl Sub<&BigInt> for &BigInt {
+    type Output = BigInt;
+    #[inline]
+    fn sub(self, rhs: &BigInt) -> BigInt {
+        self + &-rhs
+    }
+}
+
+impl Mul<&BigInt> for &BigInt {
+    type Output = BigInt;
+    #[inline]
+    fn mul(self, rhs: &BigInt) -> BigInt {
+        BigInt::from_sign_magnitude(self.negative != rhs.negative, &self.mag * &rhs.mag)
+    }
+}
+
+impl Div<&BigInt> for &BigInt {
+    type Output = BigInt;
+    #[inline]
+    fn div(self, rhs: &BigInt) -> BigInt {
+        self.div_rem(rhs).0
+    }
+}
+
+impl Rem<&BigInt> for &BigInt {
+    type Output = BigInt;
+    #[inline]
+    fn rem(self, rhs: &BigInt) -> BigInt {
+        self.div_rem(rhs).1
+    }
+}
+
+impl Shl<usize> for BigNat {
+    type Output = BigNat;
+    fn shl(self, bits: usize) -> BigNat {
+        if self.is_zero() {
+            return self;
+        }
+        let mut limbs = vec![0; bits / 64];
+        limbs.extend(shl_bits(&self.limbs, (bits % 64) as u32));
+        BigNat::from_limbs(limbs)
+    }
+}
+
+impl Shr<usize> for BigNat {
+    type Output = BigNat;
+    fn shr(self, bits: usize) -> BigNat {
+        match self.limbs.get(bits / 64..) {
+            Some(limbs) => BigNat {
+                limbs: shr_bits(limbs, (bits % 64) as u32),
+            },
+            None => BigNat::zero(),
+        }
+    }
+}
+
+//
+//Identities
+//
+
+impl Zero for BigNat {
+    #[inline]
+    fn zero() -> Self {
+        BigNat { limbs: Vec::new() }
+    }
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+}
+
+impl One for BigNat {
+    #[inline]
+    fn one() -> Self {
+        BigNat { limbs: vec![1] }
+    }
+    #[inline]
+    fn is_one(&self) -> bool {
+        self.limbs[..] == [1]
+    }
+}
+
+impl Zero for BigInt {
+    #[inline]
+    fn zero() -> Self {
+        BigInt::from(BigNat::zero())
+    }
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.mag.is_zero()
+    }
+}
+
+impl One for BigInt {
+    #[inline]
+    fn one() -> Self {
+        BigInt::from(BigNat::one())
+    }
+    #[inline]
+    fn is_one(&self) -> bool {
+        !self.negative && self.mag.is_one()
+    }
+}
+
+//
+//Ordering
+//
+
+impl PartialOrd for BigNat {
+    #[inline]
+    fn partial_cmp(&self, rhs: &Self) -> Option<Ordering> {
+        Some(self.cmp(rhs))
+    }
+}
+
+impl Ord for BigNat {
+    #[inline]
+    fn cmp(&self, rhs: &Self) -> Ordering {
+        cmp_limbs(&self.limbs, &rhs.limbs)
+    }
+}
+
+impl PartialOrd for BigInt {
+    #[inline]
+    fn partial_cmp(&self, rhs: &Self) -> Option<Ordering> {
+        Some(self.cmp(rhs))
+    }
+}
+
+impl Ord for BigInt {
+    #[inline]
+    fn cmp(&self, rhs: &Self) -> Ordering {
+        match (self.negative, rhs.negative) {
+            (false, false) => self.mag.cmp(&rhs.mag),
+            (true, true) => rhs.mag.cmp(&self.mag),
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+        }
+    }
+}
+
+impl Sign for BigNat {
+    #[inline]
+    fn signum(self) -> Self {
+        if self.is_zero() {
+            self
+        } else {
+            BigNat::one()
+        }
+    }
+    #[inline]
+    fn abs(self) -> Self {
+        self
+    }
+}
+
+impl Sign for BigInt {
+    #[inline]
+    fn signum(self) -> Self {
+        if self.is_zero() {
+            self
+        } else {
+            BigInt::from_sign_magnitude(self.negative, BigNat::one())
+        }
+    }
+    #[inline]
+    fn abs(self) -> Self {
+        BigInt::from(self.mag)
+    }
+}
+
+impl ArchimedeanDiv for BigNat {
+    #[inline]
+    fn embed_nat<N: Natural>(n: N) -> Self {
+        BigNat::one().mul_n(n)
+    }
+    #[inline]
+    fn div_arch(self, rhs: Self) -> Self {
+        self / rhs
+    }
+    #[inline]
+    fn rem_arch(self, rhs: Self) -> Self {
+        self % rhs
+    }
+    #[inline]
+    fn div_alg_arch(self, rhs: Self) -> (Self, Self) {
+        self.div_rem(&rhs)
+    }
+}
+
+impl ArchimedeanDiv for BigInt {
+    #[inline]
+    fn embed_nat<N: Natural>(n: N) -> Self {
+        BigInt::from(BigNat::embed_nat(n))
+    }
+    #[inline]
+    fn div_arch(self, rhs: Self) -> Self {
+        self.div_alg_arch(rhs).0
+    }
+    #[inline]
+    fn rem_arch(self, rhs: Self) -> Self {
+        self.div_alg_arch(rhs).1
+    }
+    fn div_alg_arch(self, rhs: Self) -> (Self, Self) {
+        let (q, r) = self.div_rem(&rhs);
+        if r.negative {
+            if rhs.negative {
+                (q + BigInt::one(), r - rhs)
+            } else {
+                (q - BigInt::one(), r + rhs)
+            }
+        } else {
+            (q, r)
+        }
+    }
+}
+
+//
+//Algebraic properties
+//
+
+macro_rules! impl_big_props {
+    ($($t:ident)*) => {$(
+        impl AddAssociative for $t {}
+        impl AddCommutative for $t {}
+        impl MulAssociative for $t {}
+        impl MulCommutative for $t {}
+        impl Distributive for $t {}
+        impl NoZeroDivisors for $t {}
+        impl UniquelyFactorizable for $t {}
+        impl AddOrdered for $t {}
+        impl MulOrdered for $t {}
+        impl ArchimedeanProperty for $t {}
+
+        impl Divisibility for $t {
+            #[inline] fn unit(&self) -> bool { self.clone().abs().is_one() }
+            #[inline] fn inverse(self) -> Option<Self> { if self.unit() { Some(self) } else { None } }
+            #[inline] fn divides(self, rhs: Self) -> bool {
+                if self.is_zero() { rhs.is_zero() } else { (rhs % self).is_zero() }
+            }
+            #[inline] fn divide(self, rhs: Self) -> Option<Self> {
+                if rhs.is_zero() {
+                    return if self.is_zero() { Some(self) } else { None };
+                }
+                let (q, r) = self.div_rem(&rhs);
+                if r.is_zero() { Some(q) } else { None }
+            }
+        }
+
+        impl Factorizable for $t {
+            type Factors = TrialDivision<Self>;
+            #[inline] fn factors(self) -> TrialDivision<Self> { TrialDivision::factors_of(self) }
+        }
+
+        impl EuclideanDiv for $t {
+            type Naturals = BigNat;
+            #[inline] fn euclid_norm(&self) -> BigNat { self.clone().abs_unsigned() }
+
+            ///Euclidean division implemented using the `/` operator
+            #[inline] fn div_euc(self, rhs: Self) -> Self { self / rhs }
+
+            ///Euclidean remainder implemented using the `%` operator
+            #[inline] fn rem_euc(self, rhs: Self) -> Self { self % rhs }
+
+            ///
+            ///Euclidean division implemented using the `/` and `%` operators
+            ///
+            ///As with the primitive integers, the remainder may be negative
+            ///
+            #[inline] fn div_alg(self, rhs: Self) -> (Self, Self) { self.div_rem(&rhs) }
+        }
+
+        impl GCD for $t {
+            ///The non-negative greatest common divisor, where `gcd(0, x) = |x|`
+            fn gcd(self, rhs: Self) -> Self {
+                let (mut a, mut b) = (self.abs(), rhs.abs());
+                while !b.is_zero() {
+                    let r = &a % &b;
+                    a = b;
+                    b = r;
+                }
+                a
+            }
+            #[inline] fn lcm(self, rhs: Self) -> Self {
+                if self.is_zero() || rhs.is_zero() {
+                    Self::zero()
+                } else {
+                    (&self * &rhs).abs() / self.gcd(rhs)
+                }
+            }
+        }
+
+        impl Primality for $t {
+            #[inline] fn irreducible(&self) -> bool { self.prime() }
+            #[inline] fn prime(&self) -> bool { baillie_psw(self.clone().abs_unsigned()) }
+        }
+    )*};
+}
+
+impl_big_props!(BigNat BigInt);
+
+impl Bezout for BigInt {
+    ///Finds `x`, `y` and the non-negative `g = gcd(self, rhs)` with `x*self + y*rhs = g`
+    fn bezout_with_gcd(self, rhs: Self) -> (Self, Self, Self) {
+        let (mut r0, mut r1) = (self, rhs);
+        let (mut x0, mut x1) = (BigInt::one(), BigInt::zero());
+        let (mut y0, mut y1) = (BigInt::zero(), BigInt::one());
+        while !r1.is_zero() {
+            let (q, r) = r0.div_rem(&r1);
+            r0 = r1;
+            r1 = r;
+            let x = &x0 - &(&q * &x1);
+            x0 = x1;
+            x1 = x;
+            let y = &y0 - &(&q * &y1);
+            y0 = y1;
+            y1 = y;
+        }
+        if r0.negative {
+            (-x0, -y0, -r0)
+        } else {
+            (x0, y0, r0)
+        }
+    }
+}
+
+impl IntegerSubset for BigNat {
+    type Signed = BigInt;
+    type Unsigned = BigNat;
+
+    #[inline]
+    fn as_signed(self) -> BigInt {
+        BigInt::from(self)
+    }
+    #[inline]
+    fn as_unsigned(self) -> BigNat {
+        self
+    }
+
+    #[inline]
+    fn two() -> Self {
+        BigNat { limbs: vec![2] }
+    }
+    #[inline]
+    fn mul_two(self) -> Self {
+        self << 1
+    }
+    #[inline]
+    fn div_two(self) -> Self {
+        self >> 1
+    }
+    #[inline]
+    fn even(&self) -> bool {
+        self.limbs.first().is_none_or(|x| x & 1 == 0)
+    }
+    #[inline]
+    fn odd(&self) -> bool {
+        !self.even()
+    }
+}
+
+impl IntegerSubset for BigInt {
+    type Signed = BigInt;
+    type Unsigned = BigNat;
+
+    #[inline]
+    fn as_signed(self) -> BigInt {
+        self
+    }
+
+    ///The magnitude of this integer, since there is no two's complement to reinterpret
+    #[inline]
+    fn as_unsigned(self) -> BigNat {
+        self.mag
+    }
+
+    #[inline]
+    fn two() -> Self {
+        BigInt::from(BigNat::two())
+    }
+    #[inline]
+    fn mul_two(self) -> Self {
+        BigInt::from_sign_magnitude(self.negative, self.mag.mul_two())
+    }
+
+    ///Halves this integer, truncating towards zero
+    #[inline]
+    fn div_two(self) -> Self {
+        BigInt::from_sign_magnitude(self.negative, self.mag.div_two())
+    }
+    #[inline]
+    fn even(&self) -> bool {
+        self.mag.even()
+    }
+    #[inline]
+    fn odd(&self) -> bool {
+        self.mag.odd()
+    }
+}
+
+impl Natural for BigNat {}
+impl Integer for BigInt {}
+
+//the product always fits, so there is no need to double-and-add
+impl MulMod for BigNat {
+    #[inline]
+    fn mul_mod(self, rhs: Self, n: &Self) -> Self {
+        self * rhs % n.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        crate::{algebra::*, analysis::ordered::*},
+        core::{convert::TryFrom, str::FromStr},
+        num_traits::{FromPrimitive, ToPrimitive},
+    };
+
+    fn nat(s: &str) -> BigNat {
+        BigNat::from_str(s).unwrap()
+    }
+
+    fn int(s: &str) -> BigInt {
+        BigInt::from_str(s).unwrap()
+    }
+
+    #[test]
+    fn parse_and_display() {
+        let x = "1852673427797059126769289382969773174697539593076302306878240055499510746836750";
+        assert_eq!(nat(x).to_string(), x);
+        assert_eq!(int(&format!("-{}", x)).to_string(), format!("-{}", x));
+        assert_eq!(nat("+000123").to_string(), "123");
+        assert_eq!(int("-0"), BigInt::zero());
+        assert_eq!(format!("{:>6}", int("-42")), "   -42");
+
+        assert!(BigNat::from_str("").is_err());
+        assert!(BigNat::from_str("-1").is_err());
+        assert!(BigInt::from_str("-+1").is_err());
+        assert!(BigInt::from_str("12a").is_err());
+    }
+
+    #[test]
+    fn conversions() {
+        assert_eq!(BigNat::from(u128::MAX).to_u128(), Some(u128::MAX));
+        assert_eq!(BigInt::from(i128::MIN).to_i128(), Some(i128::MIN));
+        assert_eq!(BigInt::from(-5i8).to_u64(), None);
+        assert_eq!(u8::try_from(BigNat::from(256u32)), Err(TryFromBigIntError));
+        assert_eq!(i64::try_from(BigInt::from(-7i32)), Ok(-7));
+        assert!(BigNat::try_from(-1i32).is_err());
+        assert_eq!(
+            BigNat::from_f64(1e30).unwrap(),
+            nat("1000000000000000019884624838656")
+        );
+        assert_eq!(BigInt::from_f64(-2.5), Some(BigInt::from(-2)));
+        assert_eq!(nat("1000000000000000019884624838656").to_f64(), Some(1e30));
+    }
+
+    #[test]
+    fn arithmetic() {
+        let a =
+            nat("1852673427797059126769289382969773174697539593076302306878240055499510746836750");
+        let b = nat("680564733841876926945195958937245974535");
+        let q = nat("2722258935367507707621680668113239015398");
+        let r = nat("227251684388451807788093493428365946820");
+
+        assert_eq!(a.clone().div_alg(b.clone()), (q.clone(), r.clone()));
+        assert_eq!(q.clone() * b.clone() + r.clone(), a);
+        assert_eq!(a.clone() - r.clone(), q * b.clone());
+
+        //the remainder of the Euclidean division is always non-negative
+        let (q, r) = (-BigInt::from(a.clone())).div_alg_arch(BigInt::from(b.clone()));
+        assert_eq!(q, int("-2722258935367507707621680668113239015399"));
+        assert_eq!(r, int("453313049453425119157102465508880027715"));
+
+        //the quotient of `/` is truncated towards zero
+        assert_eq!(int("-7") / int("2"), int("-3"));
+        assert_eq!(int("-7") % int("2"), int("-1"));
+
+        assert_eq!(
+            BigNat::from(2u8).pow_n(200u32).to_string(),
+            "1606938044258990275541962092341162602522202993782792835301376"
+        );
+        assert_eq!(
+            BigInt::from(-3).pow_n(100u32),
+            int("515377520732011331036461129765621272702107522001")
+        );
+    }
+
+    #[test]
+    fn division_identity() {
+        //compare against the multiplication for a spread of limb lengths and bit patterns
+        let mut seed = 0x9E3779B97F4A7C15u64;
+        let mut next = || {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            seed
+        };
+        for i in 1..6 {
+            for j in 1..=i {
+                let a = BigNat::from_limbs((0..i).map(|_| next()).collect());
+                let b = BigNat::from_limbs((0..j).map(|_| next() >> (next() % 64)).collect());
+                if b.is_zero() {
+                    continue;
+                }
+                let (q, r) = a.div_rem(&b);
+                assert!(r < b);
+                assert_eq!(q * b + r, a);
+            }
+        }
+    }
+
+    #[test]
+    fn gcd_and_bezout() {
+        let a =
+            int("-1852673427797059126769289382969773174697539593076302306878240055499510746836750");
+        let b = int("680564733841876926945195958937245974535");
+        let (x, y, g) = a.clone().bezout_with_gcd(b.clone());
+        assert_eq!(x * a.clone() + y * b.clone(), g);
+        assert_eq!(g, a.clone().gcd(b.clone()));
+        assert!(g.positive());
+        assert_eq!(BigInt::zero().gcd(b.clone()), b);
+        assert_eq!(int("-12").lcm(int("18")), int("36"));
+    }
+
+    #[test]
+    fn primality_and_factors() {
+        //a Mersenne prime past the fixed witness sets
+        assert!(nat("618970019642690137449562111").prime());
+        assert!(!(nat("618970019642690137449562111") * BigNat::from(3u8)).prime());
+        assert!(BigInt::from(-7).prime());
+        assert!(!BigNat::one().prime());
+
+        let x =
+            BigNat::from(2u8).pow_n(100u32) * BigNat::from(3u8).pow_n(5u32) * BigNat::from(101u8);
+        let factors: Vec<_> = x.factors().collect();
+        assert_eq!(factors.len(), 106);
+        assert_eq!(factors.iter().filter(|f| **f == BigNat::two()).count(), 100);
+        assert_eq!(factors.last(), Some(&BigNat::from(101u8)));
+
+        let factors: Vec<_> = BigInt::from(-90).factors().collect();
+        assert_eq!(
+            factors,
+            [-1, 2, 3, 3, 5]
+                .iter()
+                .map(|&f| BigInt::from(f))
+                .collect::<Vec<_>>()
+        );
+    }
+}
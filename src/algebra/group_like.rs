@@ -3,7 +3,9 @@ pub use self::{additive::*, multiplicative::*};
 ///Traits for group-like structures using addition
 pub mod additive {
     use {
-        super::{repeated_doubling, repeated_doubling_neg},
+        super::{
+            checked_repeated_doubling, mul_pow_helper, repeated_doubling, repeated_doubling_neg,
+        },
         crate::algebra::{IntegerSubset, Natural, Ring, Semiring},
         core::{convert::From, ops::Mul},
     };
@@ -60,6 +62,61 @@ pub mod additive {
 
     impl<G: AddSemigroup + Zero> MulN for G {}
 
+    ///
+    ///Multiplication by a natural number that detects overflow
+    ///
+    ///Each variant uses [repeated doubling](repeated_doubling), which never overflows in an
+    ///intermediate step unless the final result does too.
+    ///
+    pub trait CheckedMulN: MulN + CheckedAdd {
+        ///Multiplies by `n`, or returns `None` if that overflows
+        #[inline]
+        fn checked_mul_n<N: Natural>(self, n: N) -> Option<Self> {
+            checked_repeated_doubling(self, n)
+        }
+
+        ///Multiplies by `n`, saturating at the bounds of the type instead of overflowing
+        #[inline]
+        fn saturating_mul_n<N: Natural>(self, n: N) -> Self {
+            if n.is_zero() {
+                Self::zero()
+            } else {
+                mul_pow_helper(self, n, |a, b| a.saturating_add(&b))
+            }
+        }
+
+        ///Multiplies by `n`, returning the wrapped result and whether an overflow occurred
+        #[inline]
+        fn overflowing_mul_n<N: Natural>(self, n: N) -> (Self, bool) {
+            if n.is_zero() {
+                (Self::zero(), false)
+            } else {
+                mul_pow_helper((self, false), n, |(a, f), (b, g)| {
+                    let (c, h) = a.overflowing_add(&b);
+                    (c, f || g || h)
+                })
+            }
+        }
+    }
+
+    impl<G: MulN + CheckedAdd> CheckedMulN for G {}
+
+    ///
+    ///An addition operation that can report when it overflows
+    ///
+    ///For the primitive integers, these match their inherent methods of the same name. For floats,
+    ///overflowing means turning finite inputs into an infinite sum, and saturating means clamping it
+    ///to the largest finite value.
+    ///
+    pub trait CheckedAdd: AddMagma {
+        ///The sum, or `None` if it overflows
+        fn checked_add(&self, rhs: &Self) -> Option<Self>;
+        ///The sum, clamped to the bounds of the type if it overflows
+        fn saturating_add(&self, rhs: &Self) -> Self;
+        ///The sum as computed by the `+` operator and whether it overflowed
+        fn overflowing_add(&self, rhs: &Self) -> (Self, bool);
+    }
+
     pub trait MulZ: AddMonoid + Negatable {
         #[inline]
         fn mul_z<N: IntegerSubset>(self, n: N) -> Self {
@@ -123,7 +180,9 @@ pub mod additive {
 ///Traits for group-like structures using Multiplication
 pub mod multiplicative {
     use {
-        super::{repeated_squaring, repeated_squaring_inv},
+        super::{
+            checked_repeated_squaring, mul_pow_helper, repeated_squaring, repeated_squaring_inv,
+        },
         crate::algebra::{IntegerSubset, Natural},
         num_traits::Pow,
     };
@@ -162,6 +221,59 @@ pub mod multiplicative {
     }
     impl<G: MulSemigroup + One> PowN for G {}
 
+    ///
+    ///Exponentiation by a natural number that detects overflow
+    ///
+    ///Each variant uses [repeated squaring](repeated_squaring), which never overflows in an
+    ///intermediate step unless the final result does too.
+    ///
+    pub trait CheckedPowN: PowN + CheckedMul {
+        ///Raises to the power of `n`, or returns `None` if that overflows
+        #[inline]
+        fn checked_pow_n<N: Natural>(self, n: N) -> Option<Self> {
+            checked_repeated_squaring(self, n)
+        }
+
+        ///Raises to the power of `n`, saturating at the bounds of the type instead of overflowing
+        #[inline]
+        fn saturating_pow_n<N: Natural>(self, n: N) -> Self {
+            if n.is_zero() {
+                repeated_squaring(self, n)
+            } else {
+                mul_pow_helper(self, n, |a, b| a.saturating_mul(&b))
+            }
+        }
+
+        ///Raises to the power of `n`, returning the wrapped result and whether an overflow occurred
+        #[inline]
+        fn overflowing_pow_n<N: Natural>(self, n: N) -> (Self, bool) {
+            if n.is_zero() {
+                (repeated_squaring(self, n), false)
+            } else {
+                mul_pow_helper((self, false), n, |(a, f), (b, g)| {
+                    let (c, h) = a.overflowing_mul(&b);
+                    (c, f || g || h)
+                })
+            }
+        }
+    }
+
+    impl<G: PowN + CheckedMul> CheckedPowN for G {}
+
+    ///
+    ///A multiplication operation that can report when it overflows
+    ///
+    ///The multiplicative counterpart to [CheckedAdd](super::CheckedAdd)
+    ///
+    pub trait CheckedMul: MulMagma {
+        ///The product, or `None` if it overflows
+        fn checked_mul(&self, rhs: &Self) -> Option<Self>;
+        ///The product, clamped to the bounds of the type if it overflows
+        fn saturating_mul(&self, rhs: &Self) -> Self;
+        ///The product as computed by the `*` operator and whether it overflowed
+        fn overflowing_mul(&self, rhs: &Self) -> (Self, bool);
+    }
+
     pub trait PowZ: MulMonoid + Invertable {
         #[inline]
         fn pow_z<Z: IntegerSubset>(self, n: Z) -> Self {
@@ -263,6 +375,46 @@ pub fn repeated_squaring<E: Natural, R: MulMonoid + Clone>(b: R, p: E) -> R {
     }
 }
 
+//mul_pow_helper, but stopping as soon as the operation fails
+fn try_mul_pow_helper<E: Natural, R: Clone, Op: Fn(&R, &R) -> Option<R>>(
+    mut b: R,
+    mut p: E,
+    op: Op,
+) -> Option<R> {
+    let mut res = b.clone();
+    p -= E::one();
+    while !p.is_zero() {
+        if p.even() {
+            b = op(&b, &b)?;
+            p = p.div_two();
+        } else {
+            res = op(&res, &b)?;
+            p -= E::one();
+        }
+    }
+    Some(res)
+}
+
+///Raises to a natural power using repeated squaring, or returns `None` if the result overflows
+#[inline]
+pub fn checked_repeated_squaring<E: Natural, R: MulMonoid + CheckedMul>(b: R, p: E) -> Option<R> {
+    if p.is_zero() {
+        Some(repeated_squaring(b, p))
+    } else {
+        try_mul_pow_helper(b, p, R::checked_mul)
+    }
+}
+
+///Multiplies by a natural number using repeated doublings, or returns `None` if the result overflows
+#[inline]
+pub fn checked_repeated_doubling<E: Natural, R: AddMonoid + CheckedAdd>(b: R, p: E) -> Option<R> {
+    if p.is_zero() {
+        Some(R::zero())
+    } else {
+        try_mul_pow_helper(b, p, R::checked_add)
+    }
+}
+
 ///Multiplies a [monoid](AddMonoid) by a positive integer using negation and repeated doublings
 #[inline]
 pub fn repeated_doubling_neg<E: IntegerSubset, R: AddGroup>(b: R, p: E) -> R {
@@ -292,8 +444,41 @@ macro_rules! impl_props {
     (@int $z:ty) => {
         impl_props!(@props $z);
         impl_props!(@props core::num::Wrapping<$z>);
+        impl_props!(@checked $z: CheckedAdd Add add checked_add saturating_add overflowing_add);
+        impl_props!(@checked $z: CheckedMul Mul mul checked_mul saturating_mul overflowing_mul);
     };
     (@float $f:ty) => {impl_props!(@props $f);};
+
+    //integers forward to their inherent methods, while wrapping integers never overflow
+    (@checked $z:ty: $ctr:ident $tr:ident $op:ident $checked:ident $saturating:ident $overflowing:ident) => {
+        impl $ctr for $z {
+            #[inline] fn $checked(&self, rhs: &Self) -> Option<Self> { <$z>::$checked(*self, *rhs) }
+            #[inline] fn $saturating(&self, rhs: &Self) -> Self { <$z>::$saturating(*self, *rhs) }
+            #[inline] fn $overflowing(&self, rhs: &Self) -> (Self, bool) { <$z>::$overflowing(*self, *rhs) }
+        }
+        impl $ctr for core::num::Wrapping<$z> {
+            #[inline] fn $checked(&self, rhs: &Self) -> Option<Self> { Some(core::ops::$tr::$op(*self, *rhs)) }
+            #[inline] fn $saturating(&self, rhs: &Self) -> Self { core::ops::$tr::$op(*self, *rhs) }
+            #[inline] fn $overflowing(&self, rhs: &Self) -> (Self, bool) { (core::ops::$tr::$op(*self, *rhs), false) }
+        }
+    };
+
+    //floats overflow when finite inputs give an infinite result
+    (@checked_float $f:ty: $ctr:ident $tr:ident $op:ident $checked:ident $saturating:ident $overflowing:ident) => {
+        impl $ctr for $f {
+            #[inline] fn $checked(&self, rhs: &Self) -> Option<Self> {
+                match <$f as $ctr>::$overflowing(self, rhs) { (r, false) => Some(r), (_, true) => None }
+            }
+            #[inline] fn $saturating(&self, rhs: &Self) -> Self {
+                match <$f as $ctr>::$overflowing(self, rhs) { (r, false) => r, (r, true) => r.clamp(<$f>::MIN, <$f>::MAX) }
+            }
+            #[inline] fn $overflowing(&self, rhs: &Self) -> (Self, bool) {
+                let r = core::ops::$tr::$op(*self, *rhs);
+                (r, r.is_infinite() && self.is_finite() && rhs.is_finite())
+            }
+        }
+    };
+
     (@props $t:ty) => {
 
         impl AddAssociative for $t {}
@@ -304,6 +489,72 @@ macro_rules! impl_props {
 }
 
 impl_props! { usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128; f32 f64 &f32 &f64}
+impl_props!(@checked_float f32: CheckedAdd Add add checked_add saturating_add overflowing_add);
+impl_props!(@checked_float f32: CheckedMul Mul mul checked_mul saturating_mul overflowing_mul);
+impl_props!(@checked_float f64: CheckedAdd Add add checked_add saturating_add overflowing_add);
+impl_props!(@checked_float f64: CheckedMul Mul mul checked_mul saturating_mul overflowing_mul);
 
 #[cfg(std)]
 impl<'a> AddAssociative for ::std::borrow::Cow<'a, str> {}
+
+#[cfg(test)]
+mod tests {
+    use {crate::algebra::*, core::num::Wrapping};
+
+    #[test]
+    fn checked_mul_n() {
+        assert_eq!(7u8.checked_mul_n(36u32), Some(252));
+        assert_eq!(7u8.checked_mul_n(37u32), None);
+        assert_eq!(7u8.checked_mul_n(0u32), Some(0));
+        assert_eq!(7u8.saturating_mul_n(37u32), u8::MAX);
+        assert_eq!(7u8.overflowing_mul_n(37u32), (3, true));
+        assert_eq!(7u8.overflowing_mul_n(36u32), (252, false));
+
+        assert_eq!((-1i8).checked_mul_n(128u32), Some(i8::MIN));
+        assert_eq!((-1i8).checked_mul_n(129u32), None);
+        assert_eq!((-3i8).saturating_mul_n(100u32), i8::MIN);
+        assert_eq!(3i64.checked_mul_n(u64::MAX), None);
+        assert_eq!(Wrapping(7u8).checked_mul_n(37u32), Some(Wrapping(3)));
+
+        //every variant agrees with the widened product
+        for a in i8::MIN..=i8::MAX {
+            for n in 0..300u32 {
+                let exact = a as i64 * n as i64;
+                let fits = i8::try_from(exact).ok();
+                assert_eq!(a.checked_mul_n(n), fits);
+                assert_eq!(a.saturating_mul_n(n) as i64, exact.clamp(-128, 127));
+                assert_eq!(a.overflowing_mul_n(n), (exact as i8, fits.is_none()));
+            }
+        }
+    }
+
+    #[test]
+    fn checked_pow_n() {
+        assert_eq!(3u64.checked_pow_n(40u32), Some(12157665459056928801));
+        assert_eq!(3u64.checked_pow_n(41u32), None);
+        assert_eq!(0u64.checked_pow_n(100u32), Some(0));
+        assert_eq!((-2i64).checked_pow_n(63u32), Some(i64::MIN));
+        assert_eq!(2i64.checked_pow_n(63u32), None);
+        assert_eq!(2i64.saturating_pow_n(63u32), i64::MAX);
+        assert_eq!((-2i64).saturating_pow_n(65u32), i64::MIN);
+        assert_eq!(2u32.overflowing_pow_n(33u32), (0, true));
+        assert_eq!(checked_repeated_squaring(10u32, 9u32), Some(1_000_000_000));
+        assert_eq!(checked_repeated_squaring(10u32, 10u32), None);
+
+        for a in i8::MIN..=i8::MAX {
+            for n in 1..10u32 {
+                let exact = (a as i128).pow(n);
+                let fits = i8::try_from(exact).ok();
+                assert_eq!(a.checked_pow_n(n), fits);
+                assert_eq!(a.saturating_pow_n(n) as i128, exact.clamp(-128, 127));
+                assert_eq!(a.overflowing_pow_n(n), (a.wrapping_pow(n), fits.is_none()));
+            }
+        }
+
+        assert_eq!(2f64.checked_pow_n(1023u32), Some(2f64.powi(1023)));
+        assert_eq!(2f64.checked_pow_n(1024u32), None);
+        assert_eq!(10f64.saturating_pow_n(400u32), f64::MAX);
+        assert_eq!((-10f32).overflowing_pow_n(41u32), (f32::NEG_INFINITY, true));
+        assert_eq!(f32::INFINITY.checked_pow_n(2u32), Some(f32::INFINITY));
+    }
+}
@@ -6,6 +6,7 @@ use {
         ops::{Rem, RemAssign},
     },
     num_traits::{FromPrimitive, ToPrimitive},
+    smallvec::SmallVec,
 };
 
 pub trait CastPrimInt = TryFrom<i8>
@@ -91,6 +92,135 @@ pub trait IntegerSubset:
     fn odd(&self) -> bool {
         !self.even()
     }
+
+    ///
+    ///The square root of this number rounded down
+    ///
+    ///Panics if this number is negative
+    ///
+    #[inline]
+    fn isqrt(self) -> Self {
+        self.nth_root(2)
+    }
+
+    ///The cube root of this number rounded towards zero
+    #[inline]
+    fn icbrt(self) -> Self {
+        self.nth_root(3)
+    }
+
+    ///
+    ///The `n`th root of this number rounded towards zero
+    ///
+    ///By default, this uses Newton's method starting from a power of two above the root, so it
+    ///only ever needs [Euclidean division](EuclideanDiv) and never overflows.
+    ///
+    ///Panics if `n` is zero or if `n` is even and this number is negative
+    ///
+    fn nth_root(self, n: u32) -> Self {
+        if n == 0 {
+            panic!("Attempted to take the zeroth root of a number")
+        }
+        if self.negative() {
+            if n.is_multiple_of(2) {
+                panic!("Attempted to take an even root of a negative number")
+            }
+            return Self::zero() - self.abs().nth_root(n);
+        }
+        if n == 1 || self <= Self::one() {
+            return self;
+        }
+
+        //once the root is below two, it's just one
+        let bits = bit_length(&self);
+        if n >= bits {
+            return Self::one();
+        }
+
+        //x^(n-1) if it is at most a, so that a / x^(n-1) doesn't overflow
+        let pow_below = |x: &Self| {
+            let mut p = Self::one();
+            for _ in 1..n {
+                if p > self.clone() / x.clone() {
+                    return None;
+                }
+                p *= x.clone();
+            }
+            Some(p)
+        };
+
+        let n_z = Self::embed_nat(n);
+        let n_1 = Self::embed_nat(n - 1);
+        let mut x = Self::two().pow_n(bits.div_ceil(n));
+        loop {
+            let q = pow_below(&x).map_or(Self::zero(), |p| self.clone() / p);
+            let y = (n_1.clone() * x.clone() + q) / n_z.clone();
+            if y >= x {
+                return x;
+            }
+            x = y;
+        }
+    }
+
+    ///Determines if this number is the square of an integer
+    #[inline]
+    fn is_perfect_square(&self) -> bool {
+        !self.negative() && {
+            let r = self.clone().isqrt();
+            r.clone() * r == *self
+        }
+    }
+
+    ///
+    ///Determines if this number is `a^k` for some integer `a` and `k >= 2`
+    ///
+    ///Note that this includes `0`, `1` and `-1`
+    ///
+    fn is_perfect_power(&self) -> bool {
+        let a = self.clone().abs_unsigned();
+        if a <= Self::Unsigned::one() {
+            return true;
+        }
+
+        //it suffices to check prime exponents, and only odd ones for negative numbers
+        let start = if self.negative() { 3 } else { 2 };
+        (start..bit_length(&a))
+            .filter(|&k| k == 2 || (k % 2 == 1 && k.prime()))
+            .any(|k| self.clone().nth_root(k).pow_n(k) == *self)
+    }
+
+    ///
+    ///The logarithm of this number in the given base rounded down
+    ///
+    ///Panics if this number is not positive or if the base is less than two
+    ///
+    fn ilog(self, base: Self) -> u32 {
+        if self <= Self::zero() {
+            panic!("Attempted to take the logarithm of a non-positive number")
+        }
+        if base < Self::two() {
+            panic!("Attempted to take a logarithm with a base less than two")
+        }
+
+        let mut x = self;
+        let mut k = 0;
+        while x >= base {
+            x /= base.clone();
+            k += 1;
+        }
+        k
+    }
+}
+
+//the number of bits needed to write a non-negative number
+fn bit_length<Z: IntegerSubset>(x: &Z) -> u32 {
+    let mut x = x.clone();
+    let mut bits = 0;
+    while !x.is_zero() {
+        x = x.div_two();
+        bits += 1;
+    }
+    bits
 }
 
 pub trait Natural: IntegerSubset<Unsigned = Self> {}
@@ -131,7 +261,8 @@ impl<Z: IntegerSubset> Iterator for TrialDivision<Z> {
         let three = Z::embed_nat(3u8);
 
         if self.f >= three {
-            if self.f.clone().pow_n(2u8) > self.x {
+            //f^2 > x, without squaring past the end of narrow types
+            if self.f > self.x.clone() / self.f.clone() {
                 //if x is prime
                 self.f = self.x.clone();
                 self.x = Z::one();
@@ -181,6 +312,235 @@ impl<Z: IntegerSubset> Iterator for TrialDivision<Z> {
     }
 }
 
+///
+///An iterator over the prime factors of an integer using
+///[Pollard's rho algorithm](https://en.wikipedia.org/wiki/Pollard%27s_rho_algorithm)
+///
+///Small factors are first removed by trial division, and if what remains is still composite, it is
+///split using Brent's variant of the rho algorithm and [miller_rabin] until only primes are left.
+///Each prime factor `p` is found in roughly `√p` steps rather than the `p` steps [TrialDivision] needs,
+///so this is practical for any 64-bit input and for 128-bit inputs that aren't the product of two
+///large primes.
+///
+///Like [TrialDivision], the factors are given in increasing order, starting with `-1` for negative
+///numbers and with zero giving only itself.
+///
+pub struct PollardRho<Z: IntegerSubset> {
+    x: Z,
+    f: Z,
+    mode: bool,
+    found: SmallVec<[Z; 4]>,
+}
+
+//the largest factor found by trial division before switching to the rho algorithm
+const RHO_TRIAL_BOUND: u32 = 1 << 10;
+
+//how many steps of the rho algorithm to take between each gcd
+const RHO_BATCH: u64 = 128;
+
+//computes a*b mod n for 0 <= a,b < n without overflowing
+pub(crate) trait MulMod: IntegerSubset {
+    fn mul_mod(self, rhs: Self, n: &Self) -> Self;
+}
+
+impl<Z: IntegerSubset> MulMod for Z {
+    //double-and-add, using the fact that a+b overflows exactly when a >= n-b
+    default fn mul_mod(self, rhs: Self, n: &Self) -> Self {
+        #[inline]
+        fn add_mod<Z: IntegerSubset>(a: Z, b: Z, n: &Z) -> Z {
+            let c = n.clone() - b.clone();
+            if a >= c {
+                a - c
+            } else {
+                a + b
+            }
+        }
+
+        let (mut a, mut b, mut r) = (self, rhs, Z::zero());
+        while !b.is_zero() {
+            if b.odd() {
+                r = add_mod(r, a.clone(), n);
+            }
+            a = add_mod(a.clone(), a, n);
+            b = b.div_two();
+        }
+        r
+    }
+}
+
+macro_rules! impl_mul_mod {
+    ($($t:ident)*) => {$(
+        impl MulMod for $t {
+            #[inline]
+            fn mul_mod(self, rhs: Self, n: &Self) -> Self {
+                (self as u128 * rhs as u128 % *n as u128) as $t
+            }
+        }
+    )*};
+}
+impl_mul_mod!(u8 u16 u32 u64 usize i8 i16 i32 i64 isize);
+
+//finds a nontrivial factor of an odd composite n using Brent's cycle detection
+fn brent<Z: IntegerSubset>(n: &Z) -> Z {
+    let gcd = |a: Z| {
+        if a.is_zero() {
+            n.clone()
+        } else {
+            euclidean(a, n.clone())
+        }
+    };
+    let diff = |a: &Z, b: &Z| {
+        if a > b {
+            a.clone() - b.clone()
+        } else {
+            b.clone() - a.clone()
+        }
+    };
+
+    let mut c = Z::one();
+    loop {
+        let step = |y: Z| {
+            let y = y.clone().mul_mod(y, n);
+            let d = n.clone() - c.clone();
+            if y >= d {
+                y - d
+            } else {
+                y + c.clone()
+            }
+        };
+
+        let (mut x, mut y, mut ys) = (Z::two(), Z::two(), Z::two());
+        let (mut q, mut g) = (Z::one(), Z::one());
+        let mut r = 1u64;
+        while g.is_one() {
+            x = y.clone();
+            for _ in 0..r {
+                y = step(y);
+            }
+            let mut k = 0;
+            while k < r && g.is_one() {
+                ys = y.clone();
+                for _ in 0..RHO_BATCH.min(r - k) {
+                    y = step(y);
+                    q = q.mul_mod(diff(&x, &y), n);
+                }
+                g = gcd(q.clone());
+                k += RHO_BATCH;
+            }
+            r *= 2;
+        }
+
+        //if the batch overshot, backtrack one step at a time
+        if &g == n {
+            loop {
+                ys = step(ys);
+                g = gcd(diff(&x, &ys));
+                if !g.is_one() {
+                    break;
+                }
+            }
+        }
+
+        if &g != n {
+            return g;
+        }
+
+        //the cycle closed without finding a factor, so try another polynomial
+        c += Z::one();
+    }
+}
+
+impl<Z: IntegerSubset> PollardRho<Z> {
+    pub fn factors_of(x: Z) -> Self {
+        PollardRho {
+            x,
+            f: Z::two(),
+            mode: false,
+            found: SmallVec::new(),
+        }
+    }
+
+    //splits the remaining cofactor entirely, storing the primes in decreasing order
+    fn split(&mut self) {
+        let mut composites: SmallVec<[Z; 4]> = SmallVec::new();
+        composites.push(core::mem::replace(&mut self.x, Z::one()));
+        while let Some(n) = composites.pop() {
+            if n.prime() {
+                self.found.push(n);
+            } else {
+                let d = brent(&n);
+                composites.push(n / d.clone());
+                composites.push(d);
+            }
+        }
+        self.found.sort_unstable_by(|a, b| b.cmp(a));
+    }
+}
+
+impl<Z: IntegerSubset> Iterator for PollardRho<Z> {
+    type Item = Z;
+
+    fn next(&mut self) -> Option<Z> {
+        if let Some(f) = self.found.pop() {
+            return Some(f);
+        }
+        if self.x.is_one() {
+            return None;
+        }
+
+        if self.x.is_zero() {
+            self.x = Z::one();
+            return Some(Z::zero());
+        }
+        if self.x.negative() {
+            //halve even values first, since the minimum of a signed type has no absolute value
+            if self.x.even() {
+                self.x = self.x.clone().div_two().abs();
+                self.found.push(Z::two());
+            } else {
+                self.x = self.x.clone().abs();
+            }
+            return Some(Z::zero() - Z::one());
+        }
+        if self.x.even() {
+            self.x = self.x.clone().div_two();
+            return Some(Z::two());
+        }
+
+        //trial division by 2, 3, and then numbers of the form 6k±1
+        let bound = Z::from_u32(RHO_TRIAL_BOUND);
+        let three = Z::embed_nat(3u8);
+        if self.f < three {
+            self.f = three.clone();
+        }
+        while bound.as_ref().is_none_or(|b| self.f <= *b) {
+            //f^2 > x, without squaring past the end of narrow types
+            if self.f > self.x.clone() / self.f.clone() {
+                //x is prime
+                let p = core::mem::replace(&mut self.x, Z::one());
+                return Some(p);
+            }
+
+            let (q, r) = self.x.clone().div_alg(self.f.clone());
+            if r.is_zero() {
+                self.x = q;
+                return Some(self.f.clone());
+            }
+
+            if !self.mode {
+                self.mode = self.f != three;
+                self.f += Z::two();
+            } else {
+                self.mode = false;
+                self.f += Z::two() + Z::two();
+            }
+        }
+
+        self.split();
+        self.found.pop()
+    }
+}
+
 macro_rules! impl_int_subset {
 
     (@unit $self:ident @unsigned) => {*$self==1};
@@ -197,6 +557,8 @@ macro_rules! impl_int_subset {
     (@neg $self:ident @signed) => {*$self < 0 };
     (@abs $self:ident $name:ident @unsigned) => {$self};
     (@abs $self:ident $name:ident @signed) => {Sign::abs($self) };
+    (@abs_unsigned $self:ident @unsigned) => {$self};
+    (@abs_unsigned $self:ident @signed) => {$self.unsigned_abs()};
 
     //base case for loop
     ($name:ident:$signed:ident:$unsigned:ident $($tt:tt)*) => {
@@ -222,8 +584,8 @@ macro_rules! impl_int_subset {
         impl UniquelyFactorizable for $name {}
 
         impl Factorizable for $name {
-            type Factors = TrialDivision<Self>;
-            #[inline] fn factors(self) -> TrialDivision<Self> {TrialDivision::factors_of(self)}
+            type Factors = PollardRho<Self>;
+            #[inline] fn factors(self) -> PollardRho<Self> {PollardRho::factors_of(self)}
         }
 
         impl EuclideanDiv for $name {
@@ -250,12 +612,43 @@ macro_rules! impl_int_subset {
             type Unsigned = $unsigned;
             #[inline] fn as_signed(self) -> $signed { self as $signed }
             #[inline] fn as_unsigned(self) -> $unsigned { self as $unsigned }
+            #[inline] fn abs_unsigned(self) -> $unsigned { impl_int_subset!(@abs_unsigned self $($tt)*) }
 
             #[inline] fn two() -> Self { 2 }
             #[inline] fn mul_two(self) -> Self { self << 1 }
             #[inline] fn div_two(self) -> Self { self >> 1 }
             #[inline] fn even(&self) -> bool { (*self & 1) == 0 }
             #[inline] fn odd(&self) -> bool { (*self & 1) == 1 }
+
+            #[inline] fn isqrt(self) -> Self { <$name>::isqrt(self) }
+            #[inline] fn ilog(self, base: Self) -> u32 { <$name>::ilog(self, base) }
+
+            ///Estimates the root with a float and then corrects it with integer arithmetic
+            fn nth_root(self, n: u32) -> Self {
+                match n {
+                    0 => panic!("Attempted to take the zeroth root of a number"),
+                    1 => self,
+                    2 => <$name>::isqrt(self),
+                    _ => {
+                        let negative = <Self as Signed>::negative(&self);
+                        if negative && n.is_multiple_of(2) {
+                            panic!("Attempted to take an even root of a negative number")
+                        }
+
+                        //the magnitude, even for the minimum of a signed type
+                        let a = if negative { (self as $unsigned).wrapping_neg() } else { self as $unsigned };
+                        let mut r = (a as f64).powf(1.0 / n as f64) as $unsigned;
+                        while r > 0 && r.checked_pow(n).map_or(true, |p| p > a) {
+                            r -= 1;
+                        }
+                        while (r + 1).checked_pow(n).map_or(false, |p| p <= a) {
+                            r += 1;
+                        }
+
+                        if negative { (r as $name).wrapping_neg() } else { r as $name }
+                    }
+                }
+            }
         }
 
     }
@@ -281,15 +674,108 @@ macro_rules! impl_int {
     };
 }
 
+//picks a primality test by the width of the type
 macro_rules! impl_primality {
-    ($($t:ident:$hp:ident)*) => {$(
+    ($($t:ident:$hp:ident:$bases:ident)*) => {$(
         impl Primality for $t {
             #[inline] fn irreducible(&self) -> bool { self.prime() }
-            #[inline] fn prime(&self) -> bool { miller_rabin(*self as $hp) }
+            #[inline] fn prime(&self) -> bool { miller_rabin_bases(self.abs_unsigned() as $hp, $bases) }
+        }
+    )*};
+
+    //there is no deterministic set of bases this wide, so anything past 64 bits uses Baillie-PSW
+    (@wide $($t:ident)*) => {$(
+        impl Primality for $t {
+            #[inline] fn irreducible(&self) -> bool { self.prime() }
+            #[inline] fn prime(&self) -> bool {
+                let n = self.abs_unsigned();
+                match u64::try_from(n) {
+                    Ok(n) => n.prime(),
+                    Err(_) => baillie_psw(n),
+                }
+            }
         }
-    )*}
+    )*};
 }
 
 impl_int!(i8:u8 i16:u16 i32:u32 i64:u64 i128:u128 isize:usize);
-impl_primality!(i8:u16 i16:u32 i32:u64 i64:u128 i128:u128 isize:u128);
-impl_primality!(u8:u16 u16:u32 u32:u64 u64:u128 u128:u128 usize:u128);
+impl_primality!(i8:u64:MR_BASES_32 i16:u64:MR_BASES_32 i32:u64:MR_BASES_32);
+impl_primality!(u8:u64:MR_BASES_32 u16:u64:MR_BASES_32 u32:u64:MR_BASES_32);
+impl_primality!(i64:u64:MR_BASES_64 isize:u64:MR_BASES_64);
+impl_primality!(u64:u64:MR_BASES_64 usize:u64:MR_BASES_64);
+impl_primality!(@wide i128 u128);
+
+#[cfg(test)]
+mod tests {
+    use crate::algebra::*;
+
+    #[test]
+    fn roots() {
+        assert_eq!(99u8.isqrt(), 9);
+        assert_eq!(100u8.isqrt(), 10);
+        assert_eq!(u64::MAX.isqrt(), u32::MAX as u64);
+        assert_eq!(u128::MAX.icbrt(), 6981463658331);
+        assert_eq!((-27i32).icbrt(), -3);
+        assert_eq!((-28i32).icbrt(), -3);
+        assert_eq!(i64::MIN.nth_root(63), -2);
+        assert_eq!(1000u32.nth_root(1), 1000);
+        assert_eq!(u64::MAX.nth_root(64), 1);
+        assert_eq!(3u16.nth_root(5), 1);
+        assert_eq!(0i8.nth_root(7), 0);
+
+        //the primitive overrides should agree with the generic Newton iteration
+        #[cfg(feature = "std")]
+        for x in (0..5000u64)
+            .chain((0..64).map(|k| (1u64 << k) - 1))
+            .chain([u64::MAX])
+        {
+            for n in 1..10 {
+                assert_eq!(
+                    BigNat::from(x).nth_root(n),
+                    BigNat::from(x.nth_root(n)),
+                    "{} {}",
+                    x,
+                    n
+                );
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn negative_root() {
+        (-4i32).nth_root(2);
+    }
+
+    #[test]
+    fn powers() {
+        assert!(144u32.is_perfect_square());
+        assert!(!143u32.is_perfect_square());
+        assert!(!(-4i32).is_perfect_square());
+        assert!(0u8.is_perfect_square());
+
+        let powers: Vec<i32> = (-40..=40).filter(|x: &i32| x.is_perfect_power()).collect();
+        assert_eq!(
+            powers,
+            vec![-32, -27, -8, -1, 0, 1, 4, 8, 9, 16, 25, 27, 32, 36]
+        );
+        assert!((3u64.pow(40)).is_perfect_power());
+        assert!(!(3u64.pow(40) + 1).is_perfect_power());
+
+        //the minimums are (-2)^31 and (-2)^63 but have no absolute value
+        assert!(i32::MIN.is_perfect_power());
+        assert!(i64::MIN.is_perfect_power());
+        assert!(i8::MIN.is_perfect_power());
+        assert!(!(i64::MIN + 1).is_perfect_power());
+        assert_eq!(i64::MIN.abs_unsigned(), 1 << 63);
+        #[cfg(feature = "std")]
+        assert!(BigNat::from(7u128.pow(45)).is_perfect_power());
+
+        assert_eq!(1u32.ilog(10), 0);
+        assert_eq!(999u32.ilog(10), 2);
+        assert_eq!(1000u32.ilog(10), 3);
+        assert_eq!(u128::MAX.ilog(2), 127);
+        #[cfg(feature = "std")]
+        assert_eq!(BigNat::from(u128::MAX).ilog(BigNat::from(16u8)), 31);
+    }
+}
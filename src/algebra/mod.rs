@@ -13,14 +13,41 @@
 //!* ["Module-Like"](algebra::module_like) traits for groups with an added scalar multiplication operation.
 //! This includes [vector spaces](algebra::VectorSpace), [ring modules](algebra::RingModule),
 //! and [algebras](algebra::Algebra) as well as a system for [bilinear forms](algebra::BilinearForm).
+//!* Derive macros, like `#[derive(Ring)]` and `#[derive(Field)]`, for implementing the marker traits of each structure
 //!* [Integer](algebra::Integer) and [Natural](algebra::Natural) numeric traits
+//!* Deterministic [primality tests](algebra::primality) chosen by the width of the integer type
+//!* [Number-theoretic functions](algebra::number_theory) like the totient, Jacobi symbol, and Chinese Remainder Theorem
+//!* A segmented [prime sieve](algebra::sieve) along with prime counting and searching
+//!* Arbitrary-precision [BigInt](algebra::BigInt) and [BigNat](algebra::BigNat) types implementing them
+//!* [Modular integers](algebra::modular) with either a constant or runtime modulus
+//!* A concrete [Polynomial](algebra::Polynomial) type forming the ring of univariate polynomials over any [Ring](algebra::Ring)
+//!* A concrete [Rational](algebra::Rational) type giving the field of fractions of any [GCD domain](algebra::GCDDomain)
 //!
 //!For ease of use, members of each module have been re-exported into this one.
 //!
 
-pub use self::{group_like::*, integer::*, module_like::*, ring_like::*};
+pub use {
+    self::{
+        group_like::*, integer::*, modular::*, module_like::*, number_theory::*, primality::*,
+        rational::*, ring_like::*,
+    },
+    traitor_macros::{AddAbelianGroup, CommutativeRing, Field, IntegralDomain, Ring},
+};
 
+#[cfg(feature = "std")]
+pub use self::{bigint::*, polynomial::*, sieve::*};
+
+#[cfg(feature = "std")]
+pub mod bigint;
 pub mod group_like;
 pub mod integer;
+pub mod modular;
 pub mod module_like;
+pub mod number_theory;
+#[cfg(feature = "std")]
+pub mod polynomial;
+pub mod primality;
+pub mod rational;
 pub mod ring_like;
+#[cfg(feature = "std")]
+pub mod sieve;
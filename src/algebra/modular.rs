@@ -0,0 +1,631 @@
+//!
+//!Modular arithmetic over the integers modulo some `n`
+//!
+//!This module provides two representations of `ℤ/nℤ`:
+//!* [Zn], where the modulus is a compile-time constant
+//!* [DynZn], where the modulus is chosen at runtime and stored alongside each element
+//!
+//!Both implement the full set of [Field] operations regardless of the modulus, however, the
+//![inverse](Inv::inv) and division operations panic when the divisor is not a [unit](Divisibility::unit),
+//!just as the primitive integers do when dividing by zero. As such, these types _only_ truly form a
+//![Field] when the modulus is prime, which can be checked at runtime with `is_field()` using
+//![miller_rabin_bases]. For composite moduli, the fallible [Divisibility] methods should be preferred.
+//!
+
+use {
+    crate::algebra::*,
+    core::fmt::{Display, Formatter, Result as FmtResult},
+};
+
+#[inline]
+fn add_mod(a: u64, b: u64, m: u64) -> u64 {
+    ((a as u128 + b as u128) % m as u128) as u64
+}
+
+#[inline]
+fn sub_mod(a: u64, b: u64, m: u64) -> u64 {
+    if a >= b {
+        a - b
+    } else {
+        m - (b - a)
+    }
+}
+
+#[inline]
+fn mul_mod(a: u64, b: u64, m: u64) -> u64 {
+    ((a as u128 * b as u128) % m as u128) as u64
+}
+
+//the inverse of `a` modulo `m` using its Bezout coefficient with the modulus
+fn inv_mod(a: u64, m: u64) -> Option<u64> {
+    if m == 1 {
+        return Some(0);
+    }
+    let (x, _, g) = (a as i128).bezout_with_gcd(m as i128);
+    if g == 1 {
+        Some(x.rem_euclid(m as i128) as u64)
+    } else if g == -1 {
+        Some((-x).rem_euclid(m as i128) as u64)
+    } else {
+        None
+    }
+}
+
+//finds some `x` such that `b*x = a` modulo `m`, if it exists
+fn div_mod(a: u64, b: u64, m: u64) -> Option<u64> {
+    if b == 0 {
+        return if a == 0 { Some(0) } else { None };
+    }
+    let g = b.gcd(m);
+    if !a.is_multiple_of(g) {
+        return None;
+    }
+    let m = m / g;
+    inv_mod(b / g % m, m).map(|b_inv| mul_mod(a / g % m, b_inv, m))
+}
+
+///
+///An integer modulo the constant `N`
+///
+///See the [module](crate::algebra::modular) documentation for details on when this forms a [Field].
+///
+///A modulus of zero is rejected at compile time.
+///
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub struct Zn<const N: u64> {
+    value: u64,
+}
+
+impl<const N: u64> Zn<N> {
+    const NONZERO_MODULUS: () =
+        assert!(N > 0, "Attempted to construct a Zn with a modulus of zero");
+
+    ///The modulus `N`
+    pub const MODULUS: u64 = N;
+
+    ///Reduces the given integer modulo `N`
+    #[inline]
+    pub fn new(value: u64) -> Self {
+        let () = Self::NONZERO_MODULUS;
+        Zn { value: value % N }
+    }
+
+    ///The canonical representative of this element in `[0, N)`
+    #[inline]
+    pub fn value(self) -> u64 {
+        self.value
+    }
+
+    ///Determines if `ℤ/Nℤ` is a field, ie, if `N` is prime
+    #[inline]
+    pub fn is_field() -> bool {
+        miller_rabin_bases(N, MR_BASES_64)
+    }
+}
+
+impl<const N: u64> Display for Zn<N> {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "{}", self.value)
+    }
+}
+
+macro_rules! impl_zn_from {
+    (@unsigned $($t:ty)*) => {$(
+        impl<const N: u64> From<$t> for Zn<N> {
+            #[inline] fn from(x: $t) -> Self { Zn::new((x as u128 % N as u128) as u64) }
+        }
+    )*};
+    (@signed $($t:ty)*) => {$(
+        impl<const N: u64> From<$t> for Zn<N> {
+            #[inline] fn from(x: $t) -> Self { Zn::new((x as i128).rem_euclid(N as i128) as u64) }
+        }
+    )*};
+}
+
+impl_zn_from!(@unsigned u8 u16 u32 u64 u128 usize);
+impl_zn_from!(@signed i8 i16 i32 i64 i128 isize);
+
+impl<const N: u64> Neg for Zn<N> {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Zn::new(sub_mod(0, self.value, N))
+    }
+}
+
+impl<const N: u64> Inv for Zn<N> {
+    type Output = Self;
+    #[inline]
+    fn inv(self) -> Self {
+        match self.inverse() {
+            Some(x) => x,
+            None => panic!(
+                "Attempted to invert {} which is not a unit modulo {}",
+                self, N
+            ),
+        }
+    }
+}
+
+impl<const N: u64> Add for Zn<N> {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Zn::new(add_mod(self.value, rhs.value, N))
+    }
+}
+
+impl<const N: u64> Sub for Zn<N> {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Zn::new(sub_mod(self.value, rhs.value, N))
+    }
+}
+
+impl<const N: u64> Mul for Zn<N> {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        Zn::new(mul_mod(self.value, rhs.value, N))
+    }
+}
+
+impl<const N: u64> Div for Zn<N> {
+    type Output = Self;
+    #[inline]
+    fn div(self, rhs: Self) -> Self {
+        Zn::new(mul_mod(self.value, rhs.inv().value, N))
+    }
+}
+
+impl<const N: u64> AddAssign for Zn<N> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const N: u64> SubAssign for Zn<N> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<const N: u64> MulAssign for Zn<N> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<const N: u64> DivAssign for Zn<N> {
+    #[inline]
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl<const N: u64> Zero for Zn<N> {
+    #[inline]
+    fn zero() -> Self {
+        Zn::new(0)
+    }
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.value == 0
+    }
+}
+
+impl<const N: u64> One for Zn<N> {
+    #[inline]
+    fn one() -> Self {
+        Zn::new(1)
+    }
+}
+
+impl<const N: u64> AddAssociative for Zn<N> {}
+impl<const N: u64> AddCommutative for Zn<N> {}
+impl<const N: u64> MulAssociative for Zn<N> {}
+impl<const N: u64> MulCommutative for Zn<N> {}
+impl<const N: u64> Distributive for Zn<N> {}
+
+impl<const N: u64> Divisibility for Zn<N> {
+    #[inline]
+    fn divides(self, rhs: Self) -> bool {
+        div_mod(rhs.value, self.value, N).is_some()
+    }
+    #[inline]
+    fn divide(self, rhs: Self) -> Option<Self> {
+        div_mod(self.value, rhs.value, N).map(Zn::new)
+    }
+    #[inline]
+    fn unit(&self) -> bool {
+        inv_mod(self.value, N).is_some()
+    }
+    #[inline]
+    fn inverse(self) -> Option<Self> {
+        inv_mod(self.value, N).map(Zn::new)
+    }
+}
+
+///
+///An integer modulo some runtime-chosen modulus
+///
+///Since [Zero] and [One] cannot know which modulus to use, elements with a modulus of zero
+///are treated as plain integers (stored as an `i64`) that adopt the modulus of whatever element they
+///are combined with. Combining two elements with different non-zero moduli panics, while comparing
+///them just finds them unequal.
+///
+///See the [module](crate::algebra::modular) documentation for details on when this forms a [Field].
+///
+#[derive(Clone, Copy, Debug)]
+pub struct DynZn {
+    value: u64,
+    modulus: u64,
+}
+
+impl DynZn {
+    ///
+    ///Reduces the given integer by the given modulus
+    ///
+    ///A modulus of zero creates an unreduced integer that adopts the modulus of the elements it is
+    ///combined with
+    ///
+    #[inline]
+    pub fn new(value: u64, modulus: u64) -> Self {
+        if modulus == 0 {
+            DynZn { value, modulus }
+        } else {
+            DynZn {
+                value: value % modulus,
+                modulus,
+            }
+        }
+    }
+
+    ///Reduces the given signed integer by the given modulus
+    #[inline]
+    pub fn from_signed(value: i64, modulus: u64) -> Self {
+        if modulus == 0 {
+            DynZn {
+                value: value as u64,
+                modulus,
+            }
+        } else {
+            DynZn::new((value as i128).rem_euclid(modulus as i128) as u64, modulus)
+        }
+    }
+
+    ///The canonical representative of this element in `[0, modulus)`
+    #[inline]
+    pub fn value(self) -> u64 {
+        self.value
+    }
+
+    ///The modulus of this element, or zero if it has none
+    #[inline]
+    pub fn modulus(self) -> u64 {
+        self.modulus
+    }
+
+    ///Determines if the integers modulo this element's modulus form a field
+    #[inline]
+    pub fn is_field(self) -> bool {
+        miller_rabin_bases(self.modulus, MR_BASES_64)
+    }
+
+    //reduces both elements to a common modulus
+    #[inline]
+    fn unify(self, rhs: Self) -> (u64, u64, u64) {
+        match (self.modulus, rhs.modulus) {
+            (0, 0) => (self.value, rhs.value, 0),
+            (0, m) => (DynZn::from_signed(self.value as i64, m).value, rhs.value, m),
+            (m, 0) => (self.value, DynZn::from_signed(rhs.value as i64, m).value, m),
+            (m1, m2) if m1 == m2 => (self.value, rhs.value, m1),
+            (m1, m2) => panic!("Attempted to combine elements modulo {} and {}", m1, m2),
+        }
+    }
+}
+
+impl Display for DynZn {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        if self.modulus == 0 {
+            write!(f, "{}", self.value as i64)
+        } else {
+            write!(f, "{} (mod {})", self.value, self.modulus)
+        }
+    }
+}
+
+impl PartialEq for DynZn {
+    #[inline]
+    fn eq(&self, rhs: &Self) -> bool {
+        match (self.modulus, rhs.modulus) {
+            (m1, m2) if m1 != 0 && m2 != 0 && m1 != m2 => false,
+            _ => {
+                let (a, b, _) = self.unify(*rhs);
+                a == b
+            }
+        }
+    }
+}
+
+impl From<i64> for DynZn {
+    #[inline]
+    fn from(x: i64) -> Self {
+        DynZn::from_signed(x, 0)
+    }
+}
+
+impl Neg for DynZn {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        match self.modulus {
+            0 => DynZn::from_signed((self.value as i64).wrapping_neg(), 0),
+            m => DynZn::new(sub_mod(0, self.value, m), m),
+        }
+    }
+}
+
+impl Inv for DynZn {
+    type Output = Self;
+    #[inline]
+    fn inv(self) -> Self {
+        match self.inverse() {
+            Some(x) => x,
+            None => panic!("Attempted to invert {} which is not a unit", self),
+        }
+    }
+}
+
+impl Add for DynZn {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        match self.unify(rhs) {
+            (a, b, 0) => DynZn::from_signed((a as i64).wrapping_add(b as i64), 0),
+            (a, b, m) => DynZn::new(add_mod(a, b, m), m),
+        }
+    }
+}
+
+impl Sub for DynZn {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        match self.unify(rhs) {
+            (a, b, 0) => DynZn::from_signed((a as i64).wrapping_sub(b as i64), 0),
+            (a, b, m) => DynZn::new(sub_mod(a, b, m), m),
+        }
+    }
+}
+
+impl Mul for DynZn {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        match self.unify(rhs) {
+            (a, b, 0) => DynZn::from_signed((a as i64).wrapping_mul(b as i64), 0),
+            (a, b, m) => DynZn::new(mul_mod(a, b, m), m),
+        }
+    }
+}
+
+impl Div for DynZn {
+    type Output = Self;
+    #[inline]
+    fn div(self, rhs: Self) -> Self {
+        let (a, b, m) = self.unify(rhs);
+        match DynZn::new(b, m).inv() {
+            DynZn { value, modulus: 0 } => {
+                DynZn::from_signed((a as i64).wrapping_mul(value as i64), 0)
+            }
+            DynZn { value, .. } => DynZn::new(mul_mod(a, value, m), m),
+        }
+    }
+}
+
+impl AddAssign for DynZn {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for DynZn {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl MulAssign for DynZn {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl DivAssign for DynZn {
+    #[inline]
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl Zero for DynZn {
+    #[inline]
+    fn zero() -> Self {
+        DynZn::new(0, 0)
+    }
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.value == 0
+    }
+}
+
+impl One for DynZn {
+    #[inline]
+    fn one() -> Self {
+        DynZn::new(1, 0)
+    }
+}
+
+impl AddAssociative for DynZn {}
+impl AddCommutative for DynZn {}
+impl MulAssociative for DynZn {}
+impl MulCommutative for DynZn {}
+impl Distributive for DynZn {}
+
+impl Divisibility for DynZn {
+    #[inline]
+    fn divides(self, rhs: Self) -> bool {
+        rhs.divide(self).is_some()
+    }
+    #[inline]
+    fn divide(self, rhs: Self) -> Option<Self> {
+        match self.unify(rhs) {
+            (a, b, 0) => {
+                let (a, b) = (a as i64, b as i64);
+                if b != 0 && a % b == 0 {
+                    Some(DynZn::from_signed(a / b, 0))
+                } else {
+                    None
+                }
+            }
+            (a, b, m) => div_mod(a, b, m).map(|x| DynZn::new(x, m)),
+        }
+    }
+    #[inline]
+    fn unit(&self) -> bool {
+        self.inverse().is_some()
+    }
+    #[inline]
+    fn inverse(self) -> Option<Self> {
+        match self.modulus {
+            0 => match self.value as i64 {
+                1 | -1 => Some(self),
+                _ => None,
+            },
+            m => inv_mod(self.value, m).map(|x| DynZn::new(x, m)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::algebra::*;
+
+    type F7 = Zn<7>;
+    type Z12 = Zn<12>;
+
+    fn field<F: Field>(x: F, y: F) -> F {
+        (x.clone() + y.clone()) / (x * y)
+    }
+
+    #[test]
+    fn constant_modulus() {
+        assert!(F7::is_field());
+        assert!(!Z12::is_field());
+
+        assert_eq!(F7::new(5) + F7::new(4), F7::new(2));
+        assert_eq!(F7::new(2) - F7::new(5), F7::new(4));
+        assert_eq!(-F7::new(3), F7::from(-3i32));
+        assert_eq!(F7::new(3).inv(), F7::new(5));
+        assert_eq!(field(F7::new(2), F7::new(3)), F7::new(2));
+        assert_eq!(F7::new(3).pow_n(6u32), F7::one());
+        assert_eq!(F7::new(3).pow_z(-1i32), F7::new(5));
+        assert_eq!(F7::new(3).mul_n(10u64), F7::new(2));
+        assert_eq!(F7::new(3).mul_z(-1i64), F7::new(4));
+
+        let big = Zn::<18446744073709551557>::new(18446744073709551556);
+        assert_eq!(big * big, Zn::one());
+        assert_eq!(big + big, Zn::new(18446744073709551555));
+        assert!(Zn::<18446744073709551557>::is_field());
+    }
+
+    #[test]
+    fn composite_modulus() {
+        assert_eq!(Z12::new(5).inverse(), Some(Z12::new(5)));
+        assert_eq!(Z12::new(4).inverse(), None);
+        assert!(!Z12::new(4).unit());
+
+        //4x = 8 has solutions, 4x = 6 does not
+        assert!(Z12::new(4).divides(Z12::new(8)));
+        assert!(!Z12::new(4).divides(Z12::new(6)));
+        let x = Z12::new(8).divide(Z12::new(4)).unwrap();
+        assert_eq!(x * Z12::new(4), Z12::new(8));
+    }
+
+    #[test]
+    fn runtime_modulus() {
+        let x = DynZn::new(3, 7);
+        assert!(x.is_field());
+        assert_eq!(x.inv(), DynZn::new(5, 7));
+        assert_eq!(x + DynZn::one(), DynZn::new(4, 7));
+        assert_eq!(
+            x - DynZn::one() - DynZn::one() - DynZn::one(),
+            DynZn::zero()
+        );
+        assert_eq!(-DynZn::one() * x, DynZn::new(4, 7));
+        assert_eq!(x.pow_n(6u32), DynZn::one());
+        assert_eq!(x.pow_z(-2i32), DynZn::new(4, 7));
+        assert_eq!(field(x, DynZn::new(4, 7)), DynZn::new(0, 7));
+        assert_eq!(DynZn::new(4, 12).inverse(), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn mismatched_moduli() {
+        let _ = DynZn::new(1, 7) + DynZn::new(1, 11);
+    }
+
+    #[test]
+    fn primality() {
+        assert!(Zn::<2>::is_field());
+        assert!(!Zn::<1>::is_field());
+        assert!(!Zn::<561>::is_field());
+        assert!(!Zn::<{ 4294967291 * 4294967279 }>::is_field());
+        assert!(Zn::<18446744073709551557>::is_field());
+
+        assert_ne!(DynZn::new(1, 7), DynZn::new(1, 11));
+        assert_eq!(DynZn::new(8, 7), DynZn::one());
+        assert!(!DynZn::new(5, 12).is_field());
+    }
+
+    #[test]
+    fn composite_units() {
+        //units still have inverses when the modulus is composite
+        assert_eq!(Z12::new(5).inv(), Z12::new(5));
+        assert_eq!(Z12::new(7) / Z12::new(5), Z12::new(11));
+        assert_eq!(Z12::new(5).pow_z(2i32), Z12::one());
+        assert_eq!(Z12::new(5).pow_z(-1i32), Z12::new(5));
+        assert_eq!(DynZn::new(5, 12).inv(), DynZn::new(5, 12));
+        assert_eq!(DynZn::new(7, 12) / DynZn::new(5, 12), DynZn::new(11, 12));
+    }
+
+    #[test]
+    #[should_panic]
+    fn non_unit_inverse() {
+        let _ = DynZn::new(4, 12).inv();
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn polynomials() {
+        //x^2 + 1 = (x + 2)(x + 3) over F_5
+        type F5 = Zn<5>;
+        let p = Polynomial::new(vec![F5::new(1), F5::new(0), F5::new(1)]);
+        let q = Polynomial::new(vec![F5::new(2), F5::new(1)]);
+        assert_eq!(
+            p.div_alg(q),
+            (
+                Polynomial::new(vec![F5::new(3), F5::new(1)]),
+                Polynomial::zero()
+            )
+        );
+    }
+}
@@ -0,0 +1,346 @@
+//!
+//!Classical number-theoretic functions over the integers
+//!
+//!The arithmetic functions here, like the [totient] and [Möbius function](mobius), are computed
+//!from the prime factorization given by [Factorizable], while the modular ones are built on
+//![Bezout] coefficients and [Euclidean division](EuclideanDiv). All of them are generic, so they
+//!work the same for the primitives as for [BigInt](crate::algebra::BigInt).
+//!
+//!```
+//!# use traitor::algebra::*;
+//!assert_eq!(totient(36u32), 12);
+//!assert_eq!(divisor_sum(28u32), 56);
+//!assert_eq!(mod_pow(3i64, 200u32, 1000), 1);
+//!assert_eq!(mod_inv(3i64, 7), Some(5));
+//!assert_eq!(crt([(2i64, 3), (3, 5), (2, 7)]), Some((23, 105)));
+//!```
+//!
+
+use crate::algebra::{integer::MulMod, *};
+
+//calls `f` with each prime and its multiplicity in the factorization of a positive `n`
+fn prime_powers<Z, F>(n: Z, mut f: F)
+where
+    Z: IntegerSubset + Factorizable,
+    F: FnMut(Z, u32),
+{
+    if n <= Z::zero() {
+        panic!("Attempted to take an arithmetic function of a non-positive number")
+    }
+
+    let mut current: Option<(Z, u32)> = None;
+    for p in n.factors() {
+        current = match current {
+            Some((q, e)) if q == p => Some((q, e + 1)),
+            Some((q, e)) => {
+                f(q, e);
+                Some((p, 1))
+            }
+            None => Some((p, 1)),
+        };
+    }
+    if let Some((q, e)) = current {
+        f(q, e);
+    }
+}
+
+//the representative of `a` modulo `m` in `[0, m)` for positive `m`
+#[inline]
+fn reduce<Z: IntegerSubset>(a: Z, m: &Z) -> Z {
+    let r = a % m.clone();
+    if r.negative() {
+        r + m.clone()
+    } else {
+        r
+    }
+}
+
+///
+///[Euler's totient function](https://en.wikipedia.org/wiki/Euler%27s_totient_function), the number
+///of integers in `[1, n]` coprime to `n`
+///
+///Panics if `n` is not positive
+///
+pub fn totient<Z: IntegerSubset + Factorizable>(n: Z) -> Z {
+    let mut phi = n.clone();
+    prime_powers(n, |p, _| phi = phi.clone() / p.clone() * (p - Z::one()));
+    phi
+}
+
+///
+///The [Möbius function](https://en.wikipedia.org/wiki/M%C3%B6bius_function), which is zero if `n`
+///has a squared prime factor and otherwise `-1` to the number of prime factors of `n`
+///
+///Panics if `n` is not positive
+///
+pub fn mobius<Z: IntegerSubset + Factorizable>(n: Z) -> i8 {
+    let mut mu = 1;
+    prime_powers(n, |_, e| mu = if e > 1 { 0 } else { -mu });
+    mu
+}
+
+///
+///The number of positive divisors of `n`
+///
+///Panics if `n` is not positive
+///
+pub fn divisor_count<Z: IntegerSubset + Factorizable>(n: Z) -> Z {
+    let mut count = Z::one();
+    prime_powers(n, |_, e| {
+        count = count.clone() * Z::from_u32(e + 1).unwrap()
+    });
+    count
+}
+
+///
+///The sum of the positive divisors of `n`
+///
+///Panics if `n` is not positive
+///
+pub fn divisor_sum<Z: IntegerSubset + Factorizable>(n: Z) -> Z {
+    let mut sum = Z::one();
+    prime_powers(n, |p, e| {
+        //1 + p + p^2 + ... + p^e
+        let (mut term, mut s) = (Z::one(), Z::one());
+        for _ in 0..e {
+            term *= p.clone();
+            s += term.clone();
+        }
+        sum = sum.clone() * s;
+    });
+    sum
+}
+
+///
+///The [Jacobi symbol](https://en.wikipedia.org/wiki/Jacobi_symbol) `(a/n)`
+///
+///Panics if `n` is not a positive odd number
+///
+pub fn jacobi<Z: IntegerSubset>(a: Z, n: Z) -> i8 {
+    if n <= Z::zero() || n.even() {
+        panic!("Attempted to take a Jacobi symbol over a non-positive or even number")
+    }
+
+    let three = Z::embed_nat(3u8);
+    let four = Z::embed_nat(4u8);
+    let five = Z::embed_nat(5u8);
+    let eight = Z::embed_nat(8u8);
+
+    let (mut a, mut n) = (reduce(a, &n), n);
+    let mut t = 1;
+    while !a.is_zero() {
+        //(2/n) is -1 exactly when n is 3 or 5 mod 8
+        while a.even() {
+            a = a.div_two();
+            let r = n.clone() % eight.clone();
+            if r == three || r == five {
+                t = -t;
+            }
+        }
+
+        //quadratic reciprocity
+        core::mem::swap(&mut a, &mut n);
+        if a.clone() % four.clone() == three && n.clone() % four.clone() == three {
+            t = -t;
+        }
+        a %= n.clone();
+    }
+
+    if n.is_one() {
+        t
+    } else {
+        0
+    }
+}
+
+///
+///The [Legendre symbol](https://en.wikipedia.org/wiki/Legendre_symbol) `(a/p)` for an odd prime `p`
+///
+///This is `1` if `a` is a nonzero square modulo `p`, `-1` if it isn't, and `0` if `p` divides `a`.
+///Since the Legendre symbol coincides with the [Jacobi symbol](jacobi) for primes, the primality
+///of `p` is only checked in debug builds.
+///
+pub fn legendre<Z: IntegerSubset>(a: Z, p: Z) -> i8 {
+    debug_assert!(
+        p.prime(),
+        "Attempted to take a Legendre symbol over a composite number"
+    );
+    jacobi(a, p)
+}
+
+///
+///Computes `base^exp mod m` by repeated squaring, giving a result in `[0, m)`
+///
+///Panics if `m` is not positive
+///
+pub fn mod_pow<Z: IntegerSubset, E: Natural>(base: Z, mut exp: E, m: Z) -> Z {
+    if m <= Z::zero() {
+        panic!("Attempted to take a power modulo a non-positive number")
+    }
+
+    let mut b = reduce(base, &m);
+    let mut r = reduce(Z::one(), &m);
+    while !exp.is_zero() {
+        if exp.odd() {
+            r = r.mul_mod(b.clone(), &m);
+        }
+        b = b.clone().mul_mod(b, &m);
+        exp = exp.div_two();
+    }
+    r
+}
+
+///
+///Finds the inverse of `a` modulo `m` in `[0, m)`, if `a` and `m` are coprime
+///
+///Panics if `m` is not positive
+///
+pub fn mod_inv<Z: Integer + Bezout>(a: Z, m: Z) -> Option<Z> {
+    if m <= Z::zero() {
+        panic!("Attempted to take an inverse modulo a non-positive number")
+    }
+    if m.is_one() {
+        return Some(Z::zero());
+    }
+
+    let (x, _, g) = reduce(a, &m).bezout_with_gcd(m.clone());
+    if g.is_one() {
+        Some(reduce(x, &m))
+    } else if (-g).is_one() {
+        Some(reduce(-x, &m))
+    } else {
+        None
+    }
+}
+
+///
+///Solves a system of congruences `x = a_i mod m_i` using the
+///[Chinese Remainder Theorem](https://en.wikipedia.org/wiki/Chinese_remainder_theorem)
+///
+///The moduli need not be coprime, and the solution is returned as a pair `(x, m)` where `m` is the
+///least common multiple of the moduli and `x` is the unique solution in `[0, m)`. If the
+///congruences contradict each other, `None` is returned instead.
+///
+///Panics if any modulus is not positive
+///
+pub fn crt<Z, I>(congruences: I) -> Option<(Z, Z)>
+where
+    Z: Integer + Bezout,
+    I: IntoIterator<Item = (Z, Z)>,
+{
+    let (mut x, mut m) = (Z::zero(), Z::one());
+    for (a, n) in congruences {
+        if n <= Z::zero() {
+            panic!("Attempted to solve a congruence with a non-positive modulus")
+        }
+
+        //p*m + q*n = g, so x + (a-x)/g * p*m is a solution to both congruences
+        let (p, _, g) = m.clone().bezout_with_gcd(n.clone());
+        let (p, g) = if g.negative() { (-p, -g) } else { (p, g) };
+        let (q, r) = (a - x.clone()).div_alg(g.clone());
+        if !r.is_zero() {
+            return None;
+        }
+
+        let step = n / g;
+        let lcm = m.clone() * step.clone();
+        let k = reduce(q, &step).mul_mod(reduce(p, &step), &step);
+        x = reduce(x + k.mul_mod(reduce(m, &lcm), &lcm), &lcm);
+        m = lcm;
+    }
+    Some((x, m))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::algebra::*;
+
+    #[test]
+    fn arithmetic_functions() {
+        let phi: Vec<u32> = (1..=12u32).map(totient).collect();
+        assert_eq!(phi, vec![1, 1, 2, 2, 4, 2, 6, 4, 6, 4, 10, 4]);
+        let mu: Vec<i8> = (1..=12u32).map(mobius).collect();
+        assert_eq!(mu, vec![1, -1, -1, 0, -1, 1, -1, 0, 0, 1, -1, 0]);
+        let d: Vec<u32> = (1..=12u32).map(divisor_count).collect();
+        assert_eq!(d, vec![1, 2, 2, 3, 2, 4, 2, 4, 3, 4, 2, 6]);
+        let sigma: Vec<u32> = (1..=12u32).map(divisor_sum).collect();
+        assert_eq!(sigma, vec![1, 3, 4, 7, 6, 12, 8, 15, 13, 18, 12, 28]);
+
+        //against the definitions
+        for n in 1..200i64 {
+            let divisors: Vec<i64> = (1..=n).filter(|d| n % d == 0).collect();
+            assert_eq!(divisor_count(n), divisors.len() as i64);
+            assert_eq!(divisor_sum(n), divisors.iter().sum());
+            assert_eq!(totient(n), (1..=n).filter(|k| k.gcd(n) == 1).count() as i64);
+            assert_eq!(
+                divisors.iter().map(|&d| mobius(d) as i64).sum::<i64>(),
+                (n == 1) as i64
+            );
+        }
+
+        assert_eq!(totient(18446744073709551557u64), 18446744073709551556);
+        #[cfg(feature = "std")]
+        assert_eq!(
+            totient(BigInt::from(1u128 << 100)),
+            BigInt::from(1u128 << 99)
+        );
+    }
+
+    #[test]
+    fn symbols() {
+        //the quadratic residues modulo 11 are 1, 3, 4, 5, 9
+        let l: Vec<i8> = (0..11i32).map(|a| legendre(a, 11)).collect();
+        assert_eq!(l, vec![0, 1, -1, 1, 1, 1, -1, -1, -1, 1, -1]);
+        assert_eq!(legendre(-1i32, 11), -1);
+        assert_eq!(legendre(-1i32, 13), 1);
+
+        //the Jacobi symbol is multiplicative in n
+        for a in -20..20i64 {
+            assert_eq!(jacobi(a, 45), jacobi(a, 9) * jacobi(a, 5));
+        }
+        assert_eq!(jacobi(1001u64, 9907), -1);
+        assert_eq!(jacobi(19u64, 45), 1);
+    }
+
+    #[test]
+    fn modular() {
+        assert_eq!(mod_pow(2u64, 10u32, 1000), 24);
+        assert_eq!(mod_pow(-2i64, 3u8, 7), 6);
+        assert_eq!(mod_pow(5i32, 0u8, 1), 0);
+        assert_eq!(mod_pow(4i128, 13u32, 497), 445);
+
+        //residues near 2^61 multiply past the end of 64 bits
+        let p = (1 << 61) - 1;
+        assert_eq!(mod_pow(2u64, 64u32, p), 8);
+        assert_eq!(mod_pow(3u64, p - 1, p), 1);
+        assert_eq!(mod_pow(-2i64, 61u32, p as i64), p as i64 - 1);
+
+        assert_eq!(mod_inv(3i32, 11), Some(4));
+        assert_eq!(mod_inv(-3i32, 11), Some(7));
+        assert_eq!(mod_inv(6i32, 9), None);
+        #[cfg(feature = "std")]
+        assert_eq!(
+            mod_inv(BigInt::from(17), BigInt::from(3120)),
+            Some(BigInt::from(2753))
+        );
+
+        assert_eq!(crt([(2i64, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt([(-1i64, 4), (5, 6)]), Some((11, 12)));
+        assert_eq!(crt([(1i64, 4), (2, 6)]), None);
+        assert_eq!(crt(Vec::<(i64, i64)>::new()), Some((0, 1)));
+
+        let p = (1 << 61) - 1;
+        assert_eq!(
+            crt([(1i64, 3), (2, p)]),
+            Some((4611686018427387904, 6917529027641081853))
+        );
+        assert_eq!(
+            crt([(2i64, p), (1, 3)]),
+            Some((4611686018427387904, 6917529027641081853))
+        );
+        assert_eq!(
+            crt([(1i128, (1 << 40) + 15), (2, (1 << 40) - 87)]),
+            Some((414827487095513248277479, 1208925819535464337504999))
+        );
+    }
+}
@@ -0,0 +1,520 @@
+//!
+//!The ring of univariate polynomials over any [Ring]
+//!
+
+use crate::algebra::*;
+
+///
+///A univariate polynomial `c₀ + c₁x + ... + cₙxⁿ` with coefficients in the ring `R`
+///
+///The coefficients are stored in order of increasing degree with any trailing zeros removed, so
+///the zero polynomial has no coefficients at all and two polynomials are equal exactly when their
+///coefficients are.
+///
+///For any ring `R`, this forms a [ring](Ring) and an `R`-[module](RingModule), inheriting
+///[unitality](UnitalRing) and [commutativity](CommutativeRing) from `R`. Furthermore, when `R` is a
+///[Field], this is a [Euclidean domain](EuclideanDomain) using polynomial long division, and so it can be
+///used in place of the [integers](Integer) in algorithms like [euclidean] and [extended_euclidean].
+///
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Polynomial<R> {
+    coeffs: Vec<R>,
+}
+
+impl<R: Ring> Polynomial<R> {
+    ///Constructs a polynomial from its coefficients in order of increasing degree
+    #[inline]
+    pub fn new(coeffs: Vec<R>) -> Self {
+        let mut p = Polynomial { coeffs };
+        p.trim();
+        p
+    }
+
+    ///The constant polynomial `c`
+    #[inline]
+    pub fn constant(c: R) -> Self {
+        Polynomial::new(vec![c])
+    }
+
+    ///The monomial `c*xⁿ`
+    pub fn monomial(c: R, n: usize) -> Self {
+        let mut coeffs = Vec::with_capacity(n + 1);
+        coeffs.resize(n, R::zero());
+        coeffs.push(c);
+        Polynomial::new(coeffs)
+    }
+
+    ///The coefficients of this polynomial in order of increasing degree
+    #[inline]
+    pub fn coeffs(&self) -> &[R] {
+        &self.coeffs
+    }
+
+    ///Consumes this polynomial and returns its coefficients in order of increasing degree
+    #[inline]
+    pub fn into_coeffs(self) -> Vec<R> {
+        self.coeffs
+    }
+
+    ///The coefficient of `xⁿ`
+    #[inline]
+    pub fn coeff(&self, n: usize) -> R {
+        self.coeffs.get(n).cloned().unwrap_or_else(R::zero)
+    }
+
+    ///The highest power of `x` with a non-zero coefficient, or `None` for the zero polynomial
+    #[inline]
+    pub fn degree(&self) -> Option<usize> {
+        self.coeffs.len().checked_sub(1)
+    }
+
+    ///The coefficient of the highest power of `x`, or `None` for the zero polynomial
+    #[inline]
+    pub fn leading_coeff(&self) -> Option<&R> {
+        self.coeffs.last()
+    }
+
+    ///Evaluates this polynomial at the given point using Horner's method
+    pub fn eval(&self, x: R) -> R {
+        self.coeffs
+            .iter()
+            .rev()
+            .fold(R::zero(), |acc, c| acc * x.clone() + c.clone())
+    }
+
+    ///The formal derivative of this polynomial
+    pub fn derivative(&self) -> Self {
+        Polynomial::new(
+            self.coeffs
+                .iter()
+                .enumerate()
+                .skip(1)
+                .map(|(n, c)| c.clone().mul_n(n))
+                .collect(),
+        )
+    }
+
+    ///The composition `self(inner(x))` using Horner's method
+    pub fn compose(&self, inner: &Self) -> Self {
+        self.coeffs.iter().rev().fold(Self::zero(), |acc, c| {
+            acc * inner.clone() + Polynomial::constant(c.clone())
+        })
+    }
+
+    //removes any trailing zero coefficients
+    #[inline]
+    fn trim(&mut self) {
+        while self.coeffs.last().is_some_and(|c| c.is_zero()) {
+            self.coeffs.pop();
+        }
+    }
+}
+
+impl<R: UnitalRing> Polynomial<R> {
+    ///The polynomial `x`
+    #[inline]
+    pub fn x() -> Self {
+        Polynomial::monomial(R::one(), 1)
+    }
+}
+
+impl<R: Field> Polynomial<R> {
+    ///
+    ///Scales this polynomial so that its leading coefficient is one
+    ///
+    ///The zero polynomial is returned unchanged
+    ///
+    pub fn monic(self) -> Self {
+        match self.leading_coeff() {
+            Some(c) => {
+                let c = c.clone().inv();
+                self * c
+            }
+            None => self,
+        }
+    }
+}
+
+impl<R: Ring> From<R> for Polynomial<R> {
+    #[inline]
+    fn from(c: R) -> Self {
+        Polynomial::constant(c)
+    }
+}
+
+//
+//Unary operations
+//
+
+impl<R: Ring> Neg for Polynomial<R> {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Polynomial {
+            coeffs: self.coeffs.into_iter().map(|c| -c).collect(),
+        }
+    }
+}
+
+//
+//Binary operations
+//
+
+impl<R: Ring> Add for Polynomial<R> {
+    type Output = Self;
+    #[inline]
+    fn add(mut self, rhs: Self) -> Self {
+        self += rhs;
+        self
+    }
+}
+
+impl<R: Ring> Sub for Polynomial<R> {
+    type Output = Self;
+    #[inline]
+    fn sub(mut self, rhs: Self) -> Self {
+        self -= rhs;
+        self
+    }
+}
+
+impl<R: Ring> Mul for Polynomial<R> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        if self.is_zero() || rhs.is_zero() {
+            return Self::zero();
+        }
+
+        let mut coeffs = vec![R::zero(); self.coeffs.len() + rhs.coeffs.len() - 1];
+        for (i, a) in self.coeffs.iter().enumerate() {
+            for (j, b) in rhs.coeffs.iter().enumerate() {
+                coeffs[i + j] += a.clone() * b.clone();
+            }
+        }
+        Polynomial::new(coeffs)
+    }
+}
+
+impl<R: Ring> Mul<R> for Polynomial<R> {
+    type Output = Self;
+    #[inline]
+    fn mul(mut self, rhs: R) -> Self {
+        self *= rhs;
+        self
+    }
+}
+
+impl<R: Field> Div<R> for Polynomial<R> {
+    type Output = Self;
+    #[inline]
+    fn div(mut self, rhs: R) -> Self {
+        self /= rhs;
+        self
+    }
+}
+
+impl<R: Ring> AddAssign for Polynomial<R> {
+    fn add_assign(&mut self, rhs: Self) {
+        if self.coeffs.len() < rhs.coeffs.len() {
+            self.coeffs.resize(rhs.coeffs.len(), R::zero());
+        }
+        for (a, b) in self.coeffs.iter_mut().zip(rhs.coeffs) {
+            *a += b;
+        }
+        self.trim();
+    }
+}
+
+impl<R: Ring> SubAssign for Polynomial<R> {
+    fn sub_assign(&mut self, rhs: Self) {
+        if self.coeffs.len() < rhs.coeffs.len() {
+            self.coeffs.resize(rhs.coeffs.len(), R::zero());
+        }
+        for (a, b) in self.coeffs.iter_mut().zip(rhs.coeffs) {
+            *a -= b;
+        }
+        self.trim();
+    }
+}
+
+impl<R: Ring> MulAssign for Polynomial<R> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = core::mem::replace(self, Self::zero()) * rhs;
+    }
+}
+
+impl<R: Ring> MulAssign<R> for Polynomial<R> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: R) {
+        for c in self.coeffs.iter_mut() {
+            *c *= rhs.clone();
+        }
+        self.trim();
+    }
+}
+
+impl<R: Field> DivAssign<R> for Polynomial<R> {
+    #[inline]
+    fn div_assign(&mut self, rhs: R) {
+        for c in self.coeffs.iter_mut() {
+            *c /= rhs.clone();
+        }
+        self.trim();
+    }
+}
+
+//
+//Identities
+//
+
+impl<R: Ring> Zero for Polynomial<R> {
+    #[inline]
+    fn zero() -> Self {
+        Polynomial { coeffs: Vec::new() }
+    }
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.coeffs.is_empty()
+    }
+}
+
+impl<R: UnitalRing> One for Polynomial<R> {
+    #[inline]
+    fn one() -> Self {
+        Polynomial::constant(R::one())
+    }
+}
+
+//
+//Algebraic properties
+//
+
+impl<R: Ring> AddAssociative for Polynomial<R> {}
+impl<R: Ring> AddCommutative for Polynomial<R> {}
+impl<R: Ring> MulAssociative for Polynomial<R> {}
+impl<R: Ring + MulCommutative> MulCommutative for Polynomial<R> {}
+impl<R: Ring> Distributive for Polynomial<R> {}
+impl<R: Ring> Distributive<R> for Polynomial<R> {}
+impl<R: Ring + NoZeroDivisors> NoZeroDivisors for Polynomial<R> {}
+impl<R: Field> UniquelyFactorizable for Polynomial<R> {}
+
+impl<R: Field> Divisibility for Polynomial<R> {
+    #[inline]
+    fn divides(self, rhs: Self) -> bool {
+        if self.is_zero() {
+            rhs.is_zero()
+        } else {
+            rhs.rem_euc(self).is_zero()
+        }
+    }
+
+    #[inline]
+    fn divide(self, rhs: Self) -> Option<Self> {
+        if rhs.is_zero() {
+            return None;
+        }
+        let (q, r) = self.div_alg(rhs);
+        if r.is_zero() {
+            Some(q)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn unit(&self) -> bool {
+        self.degree() == Some(0)
+    }
+
+    #[inline]
+    fn inverse(self) -> Option<Self> {
+        if self.unit() {
+            Some(Polynomial::constant(self.coeffs[0].clone().inv()))
+        } else {
+            None
+        }
+    }
+}
+
+impl<R: Field> EuclideanDiv for Polynomial<R> {
+    type Naturals = usize;
+
+    ///One more than the degree, so that the zero polynomial has the smallest norm
+    #[inline]
+    fn euclid_norm(&self) -> usize {
+        self.coeffs.len()
+    }
+
+    #[inline]
+    fn div_euc(self, rhs: Self) -> Self {
+        self.div_alg(rhs).0
+    }
+
+    #[inline]
+    fn rem_euc(self, rhs: Self) -> Self {
+        self.div_alg(rhs).1
+    }
+
+    ///Polynomial long division
+    fn div_alg(self, rhs: Self) -> (Self, Self) {
+        let m = match rhs.degree() {
+            Some(m) => m,
+            None => panic!("Attempted to divide by the zero polynomial"),
+        };
+        if self.coeffs.len() <= m {
+            return (Self::zero(), self);
+        }
+
+        let n = self.coeffs.len() - 1;
+        let lead_inv = rhs.coeffs[m].clone().inv();
+        let mut r = self.coeffs;
+        let mut q = vec![R::zero(); n - m + 1];
+        for i in (0..=n - m).rev() {
+            let c = r[i + m].clone() * lead_inv.clone();
+            for (j, d) in rhs.coeffs.iter().enumerate() {
+                r[i + j] -= c.clone() * d.clone();
+            }
+            q[i] = c;
+        }
+        r.truncate(m);
+
+        (Polynomial::new(q), Polynomial::new(r))
+    }
+}
+
+///The [GCD] is always taken to be [monic](Polynomial::monic), making it unique
+impl<R: Field> GCD for Polynomial<R> {
+    fn gcd(self, rhs: Self) -> Self {
+        if self.is_zero() {
+            rhs.monic()
+        } else if rhs.is_zero() {
+            self.monic()
+        } else {
+            euclidean(self, rhs).monic()
+        }
+    }
+
+    fn lcm(self, rhs: Self) -> Self {
+        if self.is_zero() || rhs.is_zero() {
+            return Self::zero();
+        }
+        let gcd = self.clone().gcd(rhs.clone());
+        (self * rhs).div_euc(gcd).monic()
+    }
+}
+
+impl<R: Field> Bezout for Polynomial<R> {
+    fn bezout_with_gcd(self, rhs: Self) -> (Self, Self, Self) {
+        let (x, y, g) = if self.is_zero() {
+            (Self::zero(), Self::one(), rhs)
+        } else if rhs.is_zero() {
+            (Self::one(), Self::zero(), self)
+        } else {
+            extended_euclidean(self, rhs)
+        };
+
+        match g.leading_coeff() {
+            Some(c) => {
+                let c = c.clone().inv();
+                (x * c.clone(), y * c.clone(), g * c)
+            }
+            None => (x, y, g),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::algebra::*;
+
+    type Q = Rational<i64>;
+    type P = Polynomial<Q>;
+
+    fn q(n: i64) -> Q {
+        Rational::from(n)
+    }
+
+    fn p(coeffs: &[i64]) -> P {
+        Polynomial::new(coeffs.iter().map(|&c| q(c)).collect())
+    }
+
+    fn euclidean_domain<E: EuclideanDomain>(a: E, b: E) -> E {
+        a.gcd(b)
+    }
+
+    fn module<K: UnitalRing, M: RingModule<K>>(m: M, k: K) -> M {
+        m.clone() * k + m
+    }
+
+    #[test]
+    fn ring() {
+        assert_eq!(p(&[1, 2, 0, 0]).coeffs().len(), 2);
+        assert_eq!(p(&[1, 1]) * p(&[-1, 1]), p(&[-1, 0, 1]));
+        assert_eq!(p(&[1, 1]).pow_n(3u32), p(&[1, 3, 3, 1]));
+        assert_eq!(p(&[1, 2, 3]) - p(&[1, 2, 3]), P::zero());
+        assert_eq!(P::zero().degree(), None);
+        assert_eq!(P::x() * P::x(), Polynomial::monomial(q(1), 2));
+        assert_eq!(module(p(&[1, 2]), q(2)), p(&[3, 6]));
+
+        //integer coefficients don't need a field
+        let z = Polynomial::new(vec![1i32, -2, 1]);
+        assert_eq!(
+            z.clone() * z.clone(),
+            Polynomial::new(vec![1, -4, 6, -4, 1])
+        );
+        assert_eq!(z.eval(3), 4);
+    }
+
+    #[test]
+    fn calculus() {
+        let f = p(&[5, 0, -3, 2]);
+        assert_eq!(f.eval(q(2)), q(9));
+        assert_eq!(f.derivative(), p(&[0, -6, 6]));
+        assert_eq!(p(&[7]).derivative(), P::zero());
+        assert_eq!(f.compose(&p(&[1, 1])), p(&[4, 0, 3, 2]));
+        assert_eq!(p(&[0, 0, 1]).compose(&p(&[0, 0, 1])), p(&[0, 0, 0, 0, 1]));
+    }
+
+    #[test]
+    fn division() {
+        let a = p(&[-1, 0, 0, 1]);
+        let b = p(&[-1, 1]);
+        assert_eq!(a.clone().div_alg(b.clone()), (p(&[1, 1, 1]), P::zero()));
+        assert_eq!(
+            p(&[1, 0, 1]).div_alg(p(&[0, 2])),
+            (Polynomial::new(vec![q(0), Rational::new(1, 2)]), p(&[1]))
+        );
+        assert!(b.clone().divides(a.clone()));
+        assert_eq!(a.clone().divide(b.clone()), Some(p(&[1, 1, 1])));
+        assert_eq!(
+            p(&[3]).inverse(),
+            Some(Polynomial::constant(Rational::new(1, 3)))
+        );
+        assert_eq!(b.clone().inverse(), None);
+    }
+
+    #[test]
+    fn gcd() {
+        //(x-1)(x+2) and (x-1)(x-3)
+        let a = p(&[-2, 1, 1]) * q(2);
+        let b = p(&[3, -4, 1]) * q(3);
+        assert_eq!(euclidean_domain(a.clone(), b.clone()), p(&[-1, 1]));
+        assert_eq!(
+            a.clone().lcm(b.clone()),
+            p(&[-1, 1]) * p(&[2, 1]) * p(&[-3, 1])
+        );
+
+        let (x, y, g) = a.clone().bezout_with_gcd(b.clone());
+        assert_eq!(g, p(&[-1, 1]));
+        assert_eq!(x * a.clone() + y * b.clone(), g);
+
+        let (x, y, g) = extended_euclidean(a.clone(), b.clone());
+        assert_eq!(x * a + y * b, g);
+
+        assert_eq!(
+            P::zero().gcd(p(&[2, 4])),
+            Polynomial::new(vec![Rational::new(1, 2), q(1)])
+        );
+    }
+}
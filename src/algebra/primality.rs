@@ -0,0 +1,334 @@
+//!
+//!Deterministic and probabilistic primality tests for [Natural] numbers
+//!
+//!The tests here are the ones backing the [Primality] impls of the primitive integers, which pick
+//!between them by the bit width of the type:
+//!* Up to 32 bits, a [Miller-Rabin test](miller_rabin_bases) with the bases [MR_BASES_32]
+//!* Up to 64 bits, a [Miller-Rabin test](miller_rabin_bases) with the bases [MR_BASES_64]
+//!* Anything wider uses the [Baillie-PSW test](baillie_psw)
+//!
+//!The first two are proven to be exact for every input of their width. Baillie-PSW, on the other
+//!hand, is only known to be exact below `2^64`, but no composite passing it has ever been found.
+//!
+//!Unlike [miller_rabin], these never hold anything past `n` in `Z`, multiplying modulo `n` without
+//!overflowing, so every primitive can be tested in its own type.
+//!
+
+use crate::algebra::{integer::MulMod, *};
+
+///
+///Miller-Rabin bases that correctly determine the primality of every `n < 4,759,123,141`,
+///and hence every 32-bit integer
+///
+pub const MR_BASES_32: &[u64] = &[2, 7, 61];
+
+///
+///Miller-Rabin bases, found by Jim Sinclair, that correctly determine the primality of every 64-bit integer
+///
+pub const MR_BASES_64: &[u64] = &[2, 325, 9375, 28178, 450775, 9780504, 1795265022];
+
+//a^e mod n by repeated squaring
+fn pow_mod<Z: Natural>(mut a: Z, mut e: Z, n: &Z) -> Z {
+    let mut r = Z::one();
+    a %= n.clone();
+    while !e.is_zero() {
+        if e.odd() {
+            r = r.mul_mod(a.clone(), n);
+        }
+        a = a.clone().mul_mod(a, n);
+        e = e.div_two();
+    }
+    r
+}
+
+//x/2 mod n for odd n and x < n
+fn half_mod<Z: Natural>(x: Z, n: &Z) -> Z {
+    if x.even() {
+        x.div_two()
+    } else {
+        //(x+n)/2, without overflowing
+        x.div_two() + n.clone().div_two() + Z::one()
+    }
+}
+
+//a + b mod n for a, b < n
+fn add_mod<Z: Natural>(a: Z, b: Z, n: &Z) -> Z {
+    let c = n.clone() - b.clone();
+    if a >= c {
+        a - c
+    } else {
+        a + b
+    }
+}
+
+//a - b mod n for a, b < n
+fn sub_mod<Z: Natural>(a: Z, b: Z, n: &Z) -> Z {
+    if a >= b {
+        a - b
+    } else {
+        n.clone() - (b - a)
+    }
+}
+
+///
+///Determines if `n` is a strong probable prime to each of the given bases
+///
+///This is the core of the [Miller-Rabin test](miller_rabin): every prime passes for every base,
+///so `false` is always correct, while with the right set of bases, like [MR_BASES_32] or
+///[MR_BASES_64], `true` is exact for every `n` below a known bound. Bases are reduced modulo `n`
+///and any that become zero are skipped.
+///
+pub fn miller_rabin_bases<Z: Natural>(n: Z, bases: &[u64]) -> bool {
+    if n <= Z::one() {
+        return false;
+    }
+    if n == Z::two() {
+        return true;
+    }
+    if n.even() {
+        return false;
+    }
+
+    //decompose n-1 into d*2^s
+    let n_1 = n.clone() - Z::one();
+    let mut d = n_1.clone();
+    let mut s = 0u32;
+    while d.even() {
+        d = d.div_two();
+        s += 1;
+    }
+
+    let small = n.to_u64();
+    'bases: for &base in bases {
+        let a = match small {
+            Some(m) => base % m,
+            None => base,
+        };
+        if a == 0 {
+            continue;
+        }
+
+        let mut x = pow_mod(Z::from_u64(a).unwrap(), d.clone(), &n);
+        if x.is_one() || x == n_1 {
+            continue;
+        }
+        for _ in 1..s {
+            x = x.clone().mul_mod(x, &n);
+            if x == n_1 {
+                continue 'bases;
+            }
+        }
+        return false;
+    }
+    true
+}
+
+///
+///Determines if an odd `n` is a strong Lucas probable prime
+///
+///This uses the Lucas sequences with `P = 1` and `Q = (1-D)/4`, where `D` is the first of
+///`5, -7, 9, -11, ...` with a [Jacobi symbol](https://en.wikipedia.org/wiki/Jacobi_symbol) `(D/n)`
+///of `-1` (Selfridge's method A). Like [miller_rabin_bases], `false` is always correct, but the
+///composites that pass are very different from those that pass Miller-Rabin, which is what makes
+///combining the two in [baillie_psw] so effective.
+///
+pub fn strong_lucas<Z: Natural>(n: Z) -> bool {
+    if n == Z::two() {
+        return true;
+    }
+    if n <= Z::one() || n.even() {
+        return false;
+    }
+
+    //find D, keeping track of it and Q modulo n
+    let mut abs_d = 5u64;
+    let mut negative = false;
+    let (d, q) = loop {
+        let abs_d_n = Z::from_u64(abs_d).unwrap() % n.clone();
+        let d = if negative {
+            sub_mod(Z::zero(), abs_d_n, &n)
+        } else {
+            abs_d_n
+        };
+
+        match jacobi(d.clone(), n.clone()) {
+            -1 => {
+                //Q = (1-D)/4, which is (|D|+1)/4 for negative D and -(|D|-1)/4 otherwise
+                let q = if negative {
+                    Z::from_u64((abs_d + 1) / 4).unwrap() % n.clone()
+                } else {
+                    let q = Z::from_u64((abs_d - 1) / 4).unwrap() % n.clone();
+                    sub_mod(Z::zero(), q, &n)
+                };
+                break (d, q);
+            }
+            0 if Z::from_u64(abs_d).is_none_or(|x| x != n) => return false,
+            _ => {}
+        }
+
+        //a perfect square never gives -1, so check for one before searching forever
+        if abs_d == 13 && n.is_perfect_square() {
+            return false;
+        }
+        abs_d += 2;
+        negative = !negative;
+    };
+
+    //computes U_k, V_k and Q^k modulo n for k > 0
+    fn lucas<Z: Natural>(k: Z, d: &Z, q: &Z, n: &Z) -> (Z, Z, Z) {
+        if k.is_one() {
+            return (Z::one(), Z::one(), q.clone());
+        }
+
+        let (u, v, qk) = lucas(k.clone().div_two(), d, q, n);
+        let u2 = u.mul_mod(v.clone(), n);
+        let v2 = sub_mod(
+            v.clone().mul_mod(v, n),
+            add_mod(qk.clone(), qk.clone(), n),
+            n,
+        );
+        let qk2 = qk.clone().mul_mod(qk, n);
+
+        if k.even() {
+            (u2, v2, qk2)
+        } else {
+            let u = half_mod(add_mod(u2.clone(), v2.clone(), n), n);
+            let v = half_mod(add_mod(d.clone().mul_mod(u2, n), v2, n), n);
+            (u, v, qk2.mul_mod(q.clone(), n))
+        }
+    }
+
+    //decompose n+1 into k*2^s, halving first since n+1 may not fit in Z
+    let mut k = n.clone().div_two() + Z::one();
+    let mut s = 1u32;
+    while k.even() {
+        k = k.div_two();
+        s += 1;
+    }
+
+    let (u, mut v, mut qk) = lucas(k, &d, &q, &n);
+    if u.is_zero() {
+        return true;
+    }
+    for _ in 0..s {
+        if v.is_zero() {
+            return true;
+        }
+        v = sub_mod(
+            v.clone().mul_mod(v, &n),
+            add_mod(qk.clone(), qk.clone(), &n),
+            &n,
+        );
+        qk = qk.clone().mul_mod(qk, &n);
+    }
+    false
+}
+
+///
+///Determines if `n` is prime using the
+///[Baillie-PSW primality test](https://en.wikipedia.org/wiki/Baillie%E2%80%93PSW_primality_test)
+///
+///This is a [Miller-Rabin test](miller_rabin_bases) to base 2 followed by a [strong Lucas test](strong_lucas).
+///It is exact for every `n < 2^64` and, while not proven in general, no composite number is known
+///to pass it.
+///
+pub fn baillie_psw<Z: Natural>(n: Z) -> bool {
+    //trial division by the small primes handles these and speeds up most composites
+    for p in [2u8, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37] {
+        let p = Z::embed_nat(p);
+        if n == p {
+            return true;
+        }
+        if p.clone().divides(n.clone()) {
+            return false;
+        }
+    }
+    if n <= Z::one() {
+        return false;
+    }
+
+    miller_rabin_bases(n.clone(), &[2]) && strong_lucas(n)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::algebra::*;
+
+    //the first few strong pseudoprimes to base 2 and strong Lucas pseudoprimes
+    const SPSP_2: &[u64] = &[
+        2047, 3277, 4033, 4681, 8321, 15841, 29341, 42799, 49141, 52633,
+    ];
+    const SLPSP: &[u64] = &[
+        5459, 5777, 10877, 16109, 18971, 22499, 24569, 25199, 40309, 58519,
+    ];
+
+    #[test]
+    fn pseudoprimes() {
+        for &n in SPSP_2 {
+            assert!(miller_rabin_bases(n, &[2]), "{}", n);
+            assert!(!strong_lucas(n), "{}", n);
+            assert!(!baillie_psw(n), "{}", n);
+        }
+        for &n in SLPSP {
+            assert!(strong_lucas(n), "{}", n);
+            assert!(!miller_rabin_bases(n, &[2]), "{}", n);
+            assert!(!baillie_psw(n), "{}", n);
+        }
+
+        //a strong pseudoprime to every base below 41 needs the deterministic sets
+        let n = 3825123056546413051u128;
+        assert!(miller_rabin_bases(n, &[2, 3, 5, 7, 11, 13, 17, 19, 23]));
+        assert!(!miller_rabin_bases(n, MR_BASES_64));
+        assert!(!baillie_psw(n));
+    }
+
+    #[test]
+    fn agreement() {
+        //every test should agree with the simple sieve on small numbers
+        let mut sieve = [true; 2000];
+        sieve[0] = false;
+        sieve[1] = false;
+        for i in 2..sieve.len() {
+            if sieve[i] {
+                for j in (i * i..sieve.len()).step_by(i) {
+                    sieve[j] = false;
+                }
+            }
+        }
+        for (n, &prime) in sieve.iter().enumerate() {
+            let n = n as u64;
+            assert_eq!(miller_rabin_bases(n, MR_BASES_32), prime, "{}", n);
+            assert_eq!(miller_rabin_bases(n, MR_BASES_64), prime, "{}", n);
+            assert_eq!(baillie_psw(n), prime, "{}", n);
+            assert_eq!((n as u16).prime(), prime, "{}", n);
+        }
+    }
+
+    #[test]
+    fn widths() {
+        assert!(4294967291u32.prime());
+        assert!(!4294967295u32.prime());
+        assert!(18446744073709551557u64.prime());
+        assert!(!18446744073709551559u64.prime());
+
+        //2^127 - 1 and 2^89 - 1 are Mersenne primes while 2^67 - 1 famously isn't
+        assert!(170141183460469231731687303715884105727u128.prime());
+        assert!(618970019642690137449562111u128.prime());
+        assert!(!147573952589676412927u128.prime());
+        assert!(!(18446744073709551557u128 * 1000000007).prime());
+
+        //past 2^127, where sums modulo n no longer fit in the type
+        assert!((u128::MAX - 158).prime());
+        assert!(!u128::MAX.prime());
+        assert!(!(18446744073709551557u128 * 18446744073709551557).prime());
+        assert!((i128::MIN + 1).prime());
+        assert!(baillie_psw(u128::MAX - 158));
+
+        #[cfg(feature = "std")]
+        {
+            let p: BigNat = "170141183460469231731687303715884105727".parse().unwrap();
+            assert!(p.prime());
+            assert!(!(&p * &p).prime());
+        }
+    }
+}
@@ -0,0 +1,471 @@
+//!
+//!A generic field of fractions over any [GCD domain](GCDDomain)
+//!
+
+use {
+    crate::{algebra::*, analysis::ordered::*},
+    core::{
+        cmp::Ordering,
+        fmt::{Display, Formatter, Result as FmtResult},
+    },
+    num_traits::ToPrimitive,
+};
+
+///
+///A fraction `n/d` of two elements of a [GCD domain](GCDDomain)
+///
+///Every constructor reduces the fraction by the [GCD] of its numerator and denominator, and if
+///`T` has a [Sign], the denominator is also kept positive. As such, for the [integers](Integer),
+///each rational number has exactly one representation and the derived equality is exact.
+///
+///With `T` an [Integer], this type is an [ordered](OrdField) and [archimedean](ArchimedeanDiv) field.
+///
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Rational<T> {
+    n: T,
+    d: T,
+}
+
+//moves any negative sign from the denominator to the numerator whenever T has a sign
+trait SignNormalize: Sized {
+    fn _sign_normalize(n: Self, d: Self) -> (Self, Self);
+}
+impl<T: GCDDomain> SignNormalize for T {
+    #[inline(always)]
+    default fn _sign_normalize(n: Self, d: Self) -> (Self, Self) {
+        (n, d)
+    }
+}
+impl<T: GCDDomain + Sign> SignNormalize for T {
+    #[inline]
+    fn _sign_normalize(n: Self, d: Self) -> (Self, Self) {
+        if d.negative() {
+            (-n, -d)
+        } else {
+            (n, d)
+        }
+    }
+}
+
+impl<T: GCDDomain> Rational<T> {
+    ///
+    ///Constructs the reduced fraction `numerator/denominator`
+    ///
+    ///Panics if the denominator is zero
+    ///
+    pub fn new(numerator: T, denominator: T) -> Self {
+        if denominator.is_zero() {
+            panic!("Attempted to construct a Rational with a zero denominator")
+        }
+        if numerator.is_zero() {
+            return Rational {
+                n: T::zero(),
+                d: T::one(),
+            };
+        }
+
+        let gcd = numerator.clone().gcd(denominator.clone());
+        let n = numerator.clone().divide(gcd.clone());
+        let d = denominator.clone().divide(gcd);
+        let (n, d) = match (n, d) {
+            (Some(n), Some(d)) => (n, d),
+            _ => (numerator, denominator),
+        };
+        let (n, d) = T::_sign_normalize(n, d);
+        Rational { n, d }
+    }
+
+    ///The numerator of this fraction in lowest terms
+    #[inline]
+    pub fn numer(&self) -> &T {
+        &self.n
+    }
+
+    ///The denominator of this fraction in lowest terms
+    #[inline]
+    pub fn denom(&self) -> &T {
+        &self.d
+    }
+
+    ///Splits this fraction into its numerator and denominator
+    #[inline]
+    pub fn into_parts(self) -> (T, T) {
+        (self.n, self.d)
+    }
+}
+
+impl<T: GCDDomain + PartialEq> Rational<T> {
+    ///Determines if this fraction has a denominator of one
+    #[inline]
+    pub fn is_integral(&self) -> bool {
+        self.d.is_one()
+    }
+}
+
+impl<T: GCDDomain + ToPrimitive> Rational<T> {
+    ///
+    ///Approximates this fraction as a 64-bit float
+    ///
+    ///Mirrors [Real::approx](crate::analysis::Real::approx). Returns `NaN` if either part of
+    ///the fraction cannot be represented as an `f64`.
+    ///
+    pub fn approx(&self) -> f64 {
+        match (self.n.to_f64(), self.d.to_f64()) {
+            (Some(n), Some(d)) => n / d,
+            _ => f64::NAN,
+        }
+    }
+}
+
+impl<T: GCDDomain + ArchimedeanDiv + Sign> Rational<T> {
+    ///Rounds this fraction to the closest integer downward
+    #[inline]
+    pub fn floor(self) -> Self {
+        Rational::from(self.n.div_arch(self.d))
+    }
+
+    ///Rounds this fraction to the closest integer upward
+    #[inline]
+    pub fn ceil(self) -> Self {
+        -(-self).floor()
+    }
+
+    ///Rounds this fraction to the closest integer, rounding half-way cases away from zero
+    #[inline]
+    pub fn round(self) -> Self {
+        let half = Rational::new(T::one(), T::embed_nat(2u8));
+        if self.n.negative() {
+            -(half - self).floor()
+        } else {
+            (self + half).floor()
+        }
+    }
+
+    ///Rounds this fraction towards zero
+    #[inline]
+    pub fn trunc(self) -> Self {
+        if self.n.negative() {
+            self.ceil()
+        } else {
+            self.floor()
+        }
+    }
+
+    ///The difference between this fraction and its [truncation](Rational::trunc)
+    #[inline]
+    pub fn fract(self) -> Self {
+        self.clone() - self.trunc()
+    }
+}
+
+impl<T: GCDDomain> From<T> for Rational<T> {
+    #[inline]
+    fn from(n: T) -> Self {
+        Rational { n, d: T::one() }
+    }
+}
+
+impl<T: GCDDomain + PartialEq + Display> Display for Rational<T> {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        if self.d.is_one() {
+            write!(f, "{}", self.n)
+        } else {
+            write!(f, "{}/{}", self.n, self.d)
+        }
+    }
+}
+
+//
+//Unary operations
+//
+
+impl<T: GCDDomain> Neg for Rational<T> {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Rational {
+            n: -self.n,
+            d: self.d,
+        }
+    }
+}
+
+impl<T: GCDDomain> Inv for Rational<T> {
+    type Output = Self;
+    #[inline]
+    fn inv(self) -> Self {
+        Rational::new(self.d, self.n)
+    }
+}
+
+//
+//Binary operations
+//
+
+impl<T: GCDDomain> Add for Rational<T> {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Rational::new(
+            self.n * rhs.d.clone() + rhs.n * self.d.clone(),
+            self.d * rhs.d,
+        )
+    }
+}
+
+impl<T: GCDDomain> Sub for Rational<T> {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Rational::new(
+            self.n * rhs.d.clone() - rhs.n * self.d.clone(),
+            self.d * rhs.d,
+        )
+    }
+}
+
+impl<T: GCDDomain> Mul for Rational<T> {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        Rational::new(self.n * rhs.n, self.d * rhs.d)
+    }
+}
+
+impl<T: GCDDomain> Div for Rational<T> {
+    type Output = Self;
+    #[inline]
+    fn div(self, rhs: Self) -> Self {
+        Rational::new(self.n * rhs.d, self.d * rhs.n)
+    }
+}
+
+impl<T: GCDDomain> AddAssign for Rational<T> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = self.clone() + rhs;
+    }
+}
+
+impl<T: GCDDomain> SubAssign for Rational<T> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = self.clone() - rhs;
+    }
+}
+
+impl<T: GCDDomain> MulAssign for Rational<T> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = self.clone() * rhs;
+    }
+}
+
+impl<T: GCDDomain> DivAssign for Rational<T> {
+    #[inline]
+    fn div_assign(&mut self, rhs: Self) {
+        *self = self.clone() / rhs;
+    }
+}
+
+//
+//Identities
+//
+
+impl<T: GCDDomain> Zero for Rational<T> {
+    #[inline]
+    fn zero() -> Self {
+        Rational::from(T::zero())
+    }
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.n.is_zero()
+    }
+}
+
+impl<T: GCDDomain + PartialEq> One for Rational<T> {
+    #[inline]
+    fn one() -> Self {
+        Rational::from(T::one())
+    }
+    #[inline]
+    fn is_one(&self) -> bool {
+        self.n.is_one() && self.d.is_one()
+    }
+}
+
+//
+//Algebraic properties
+//
+
+impl<T: GCDDomain> AddAssociative for Rational<T> {}
+impl<T: GCDDomain> AddCommutative for Rational<T> {}
+impl<T: GCDDomain> MulAssociative for Rational<T> {}
+impl<T: GCDDomain> MulCommutative for Rational<T> {}
+impl<T: GCDDomain> Distributive for Rational<T> {}
+impl<T: GCDDomain> NoZeroDivisors for Rational<T> {}
+impl<T: GCDDomain> UniquelyFactorizable for Rational<T> {}
+
+impl<T: GCDDomain> Divisibility for Rational<T> {
+    #[inline]
+    fn divides(self, rhs: Self) -> bool {
+        !self.is_zero() || rhs.is_zero()
+    }
+    #[inline]
+    fn divide(self, rhs: Self) -> Option<Self> {
+        if rhs.is_zero() {
+            None
+        } else {
+            Some(self / rhs)
+        }
+    }
+    #[inline]
+    fn unit(&self) -> bool {
+        !self.is_zero()
+    }
+    #[inline]
+    fn inverse(self) -> Option<Self> {
+        if self.is_zero() {
+            None
+        } else {
+            Some(self.inv())
+        }
+    }
+}
+
+//
+//Ordering
+//
+//Since the denominators are kept positive by `new()`, comparing the cross-multiplied
+//numerators is enough to compare the fractions
+//
+
+impl<T: GCDDomain + Ord + Sign> PartialOrd for Rational<T> {
+    #[inline]
+    fn partial_cmp(&self, rhs: &Self) -> Option<Ordering> {
+        Some(self.cmp(rhs))
+    }
+}
+
+impl<T: GCDDomain + Ord + Sign> Ord for Rational<T> {
+    #[inline]
+    fn cmp(&self, rhs: &Self) -> Ordering {
+        (self.n.clone() * rhs.d.clone()).cmp(&(rhs.n.clone() * self.d.clone()))
+    }
+}
+
+impl<T: GCDDomain + Ord + Sign> AddOrdered for Rational<T> {}
+impl<T: GCDDomain + Ord + Sign> MulOrdered for Rational<T> {}
+impl<T: GCDDomain + Ord + Sign> ArchimedeanProperty for Rational<T> {}
+
+impl<T: GCDDomain + Ord + Sign> Sign for Rational<T> {
+    #[inline]
+    fn signum(self) -> Self {
+        Rational::from(self.n.signum())
+    }
+    #[inline]
+    fn abs(self) -> Self {
+        Rational {
+            n: self.n.abs(),
+            d: self.d,
+        }
+    }
+}
+
+impl<T: GCDDomain + Ord + Sign + ArchimedeanDiv> ArchimedeanDiv for Rational<T> {
+    #[inline]
+    fn embed_nat<N: Natural>(n: N) -> Self {
+        Rational::from(T::embed_nat(n))
+    }
+    #[inline]
+    fn div_arch(self, rhs: Self) -> Self {
+        self.div_alg_arch(rhs).0
+    }
+    #[inline]
+    fn rem_arch(self, rhs: Self) -> Self {
+        self.div_alg_arch(rhs).1
+    }
+    #[inline]
+    fn div_alg_arch(self, rhs: Self) -> (Self, Self) {
+        let q = (self.clone() / rhs.clone().abs()).floor();
+        let q = if rhs.negative() { -q } else { q };
+        let r = self - q.clone() * rhs;
+        (q, r)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{algebra::*, analysis::*};
+
+    fn mul_add<R: Ring>(a: R, b: R, c: R) -> R {
+        a * b + c
+    }
+
+    fn ord_field<F: OrdField>(x: F) -> F {
+        x.abs()
+    }
+
+    fn arch_field<F: ArchField>(x: F, y: F) -> (F, F) {
+        x.div_alg_arch(y)
+    }
+
+    #[test]
+    fn normalization() {
+        assert_eq!(Rational::new(2, 4), Rational::new(1, 2));
+        assert_eq!(Rational::new(-2, -4), Rational::new(1, 2));
+        assert_eq!(Rational::new(2, -4), Rational::new(-1, 2));
+        assert_eq!(Rational::new(-3, 6).into_parts(), (-1, 2));
+        assert_eq!(Rational::new(0, -7).into_parts(), (0, 1));
+        assert_eq!(*Rational::new(4i64, -6).denom(), 3);
+    }
+
+    #[test]
+    fn arithmetic() {
+        let half = Rational::new(1, 2);
+        let two_thirds = Rational::new(2, 3);
+        let sixth = Rational::new(1, 6);
+
+        assert_eq!(mul_add(half, two_thirds, sixth), half);
+        assert_eq!(half.pow_n(7u32), Rational::new(1, 128));
+        assert_eq!(half.pow_z(-3i32), Rational::new(8, 1));
+        assert_eq!(half - two_thirds, -sixth);
+        assert_eq!(half / two_thirds, Rational::new(3, 4));
+        assert_eq!(Rational::new(0i8, 1).inverse(), None);
+        assert_eq!(Rational::new(-3i8, 4).inverse(), Some(Rational::new(-4, 3)));
+
+        let zero = Rational::new(0, 1);
+        assert!(zero.divides(zero));
+        assert!(!zero.divides(half));
+        assert!(half.divides(zero));
+    }
+
+    #[test]
+    fn ordering() {
+        assert!(Rational::new(1, 3) < Rational::new(1, 2));
+        assert!(Rational::new(-1, 2) < Rational::new(-1, 3));
+        assert_eq!(ord_field(Rational::new(-5, 3)), Rational::new(5, 3));
+        assert_eq!(Rational::new(-5, 3).signum(), Rational::new(-1, 1));
+    }
+
+    #[test]
+    fn rounding() {
+        let x = Rational::new(-7, 2);
+        assert_eq!(x.floor(), Rational::from(-4));
+        assert_eq!(x.ceil(), Rational::from(-3));
+        assert_eq!(x.round(), Rational::from(-4));
+        assert_eq!(x.trunc(), Rational::from(-3));
+        assert_eq!(x.fract(), Rational::new(-1, 2));
+        assert_eq!(Rational::new(5, 4).round(), Rational::from(1));
+        assert_eq!(Rational::new(3, 8).approx(), 0.375);
+
+        assert_eq!(
+            arch_field(Rational::new(7, 2), Rational::from(-2)),
+            (Rational::from(-1), Rational::new(3, 2))
+        );
+        assert_eq!(
+            arch_field(Rational::new(-7, 2), Rational::from(2)),
+            (Rational::from(-2), Rational::new(1, 2))
+        );
+    }
+}
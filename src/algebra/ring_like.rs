@@ -261,6 +261,9 @@ where
 ///guess that means that for super huge numbers, this technically _could_ give a false positive... ¯\\\_(ツ)\_/¯
 ///But hey, what _else_ is there? The AKS Primality Test?
 ///
+///The [primality](crate::algebra::primality) module has the tests with proven sets of bases that
+///the primitive integers actually use, along with the [Baillie-PSW test](baillie_psw) for wider types.
+///
 pub fn miller_rabin<Z: Natural>(n: Z) -> bool {
     //trivial cases
     if n <= Z::one() {
@@ -393,8 +396,19 @@ pub fn miller_rabin<Z: Natural>(n: Z) -> bool {
             }
     } else {
         //in general, we need to check every witness below 2*ln(n)^2
+        //and since n < 2^bits, that is at most 2*(bits*ln(2))^2
+        let mut bits = 0u64;
+        let mut m = n.clone();
+        while !m.is_zero() {
+            m = m.div_two();
+            bits += 1;
+        }
+        let ln_n = bits as f64 * core::f64::consts::LN_2;
+        let bound = Z::from_u64((2.0 * ln_n * ln_n) as u64)
+            .map_or(n.clone() - Z::one(), |b| b.min(n.clone() - Z::one()));
+
         let mut a = Z::two();
-        while Z::try_from(3.pow_n(a.clone().div_two())).unwrap_or(n.clone()) < n.clone().mul_two() {
+        while a <= bound {
             if !_witness(a.clone(), d.clone(), s.clone(), n.clone()) {
                 return false;
             }
@@ -406,10 +420,132 @@ pub fn miller_rabin<Z: Natural>(n: Z) -> bool {
 
 #[cfg(test)]
 mod tests {
-    use crate::algebra::*;
+    use {crate::algebra::*, traitor_macros::auto_gen_impl};
 
     //TODO: add tests for euclidean and extended_euclidean
 
+    #[test]
+    fn derives() {
+        fn assert_field<K: Field>(_: &K) {}
+        fn assert_commutative_ring<R: CommutativeRing>(_: &R) {}
+        fn assert_domain<R: IntegralDomain>(_: &R) {}
+
+        #[derive(Clone, Copy, PartialEq, Debug, Field)]
+        #[traitor(component_wise)]
+        struct Real(f64);
+
+        #[derive(Clone, Copy, PartialEq, Debug, CommutativeRing)]
+        #[traitor(component_wise)]
+        struct Pair {
+            a: i32,
+            b: i64,
+        }
+
+        //only the markers for a type with its own operations
+        #[derive(Clone, Copy, PartialEq, Debug, IntegralDomain)]
+        struct Wrapped(i64);
+
+        impl Add for Wrapped {
+            type Output = Self;
+            fn add(self, rhs: Self) -> Self {
+                Wrapped(self.0 + rhs.0)
+            }
+        }
+        impl AddAssign for Wrapped {
+            fn add_assign(&mut self, rhs: Self) {
+                self.0 += rhs.0
+            }
+        }
+        impl Sub for Wrapped {
+            type Output = Self;
+            fn sub(self, rhs: Self) -> Self {
+                Wrapped(self.0 - rhs.0)
+            }
+        }
+        impl SubAssign for Wrapped {
+            fn sub_assign(&mut self, rhs: Self) {
+                self.0 -= rhs.0
+            }
+        }
+        impl Neg for Wrapped {
+            type Output = Self;
+            fn neg(self) -> Self {
+                Wrapped(-self.0)
+            }
+        }
+        impl Mul for Wrapped {
+            type Output = Self;
+            fn mul(self, rhs: Self) -> Self {
+                Wrapped(self.0 * rhs.0)
+            }
+        }
+        impl MulAssign for Wrapped {
+            fn mul_assign(&mut self, rhs: Self) {
+                self.0 *= rhs.0
+            }
+        }
+        impl Zero for Wrapped {
+            fn zero() -> Self {
+                Wrapped(0)
+            }
+            fn is_zero(&self) -> bool {
+                self.0 == 0
+            }
+        }
+        impl One for Wrapped {
+            fn one() -> Self {
+                Wrapped(1)
+            }
+        }
+        impl Divisibility for Wrapped {
+            fn divides(self, rhs: Self) -> bool {
+                self.0.divides(rhs.0)
+            }
+            fn divide(self, rhs: Self) -> Option<Self> {
+                self.0.divide(rhs.0).map(Wrapped)
+            }
+            fn unit(&self) -> bool {
+                self.0.unit()
+            }
+            fn inverse(self) -> Option<Self> {
+                self.0.inverse().map(Wrapped)
+            }
+        }
+
+        let x = Real(2.0);
+        assert_field(&x);
+        assert_eq!(x * x.inv() + Real::one(), Real(2.0));
+        assert_eq!(x / Real(4.0) - Real::zero(), Real(0.5));
+
+        let p = Pair { a: 2, b: -3 };
+        assert_commutative_ring(&p);
+        assert_eq!(p * p + Pair::one(), Pair { a: 5, b: 10 });
+        assert!((-p + p).is_zero());
+
+        assert_domain(&Wrapped(2));
+        assert_eq!(Wrapped(2).pow_n(3u32), Wrapped(8));
+    }
+
+    #[auto_gen_impl(ScaleConstraint)]
+    pub(crate) trait Scale<K: Semiring>: Clone + Mul<K, Output = Self>
+    where
+        K: Clone,
+    {
+        fn scale_twice(&self, k: K) -> Self {
+            self.clone() * k.clone() * k
+        }
+    }
+
+    #[test]
+    fn auto_gen_generics() {
+        fn scaled<K: Semiring + Clone, T: Scale<K>>(x: T, k: K) -> T {
+            x.scale_twice(k)
+        }
+
+        assert_eq!(scaled(3, 2), 12);
+        assert_eq!(scaled(1.5, 2.0), 6.0);
+    }
+
     #[test]
     fn primality() {
         assert!(18446744073709551557u64.prime());
@@ -512,4 +648,77 @@ mod tests {
         assert_eq!(factors_slice(0x344CAF57AB24A9i64, &mut factors), 8);
         assert_eq!(&factors, &[101, 101, 103, 103, 107, 107, 109, 109, 109, 0]);
     }
+
+    #[test]
+    fn factor_narrow() {
+        //checks that the factors are prime, in order, and multiply back to n
+        fn check<Z: IntegerSubset + Factorizable + Into<i64>>(n: Z) {
+            let mut product = 1i64;
+            let mut last = 0i64;
+            for f in n.clone().factors() {
+                let f: i64 = f.into();
+                if f != -1 {
+                    assert!(
+                        f.prime() && f >= last,
+                        "{} in the factors of {}",
+                        f,
+                        product
+                    );
+                    last = f;
+                }
+                product *= f;
+            }
+            assert_eq!(product, n.into());
+        }
+
+        (1..=u8::MAX).for_each(check);
+        (1..=u16::MAX).for_each(check);
+        (i8::MIN..=i8::MAX).filter(|n| *n != 0).for_each(check);
+        (i16::MIN..=i16::MAX).filter(|n| *n != 0).for_each(check);
+
+        assert_eq!(mobius(251u8), -1);
+        assert_eq!(mobius(65521u16), -1);
+    }
+
+    #[test]
+    fn factor_large() {
+        #[cfg(feature = "std")]
+        {
+            //semiprimes with no small factors, which trial division could never finish
+            assert_eq!(
+                18446743979220271189u64.factors().collect::<Vec<_>>(),
+                vec![4294967279, 4294967291]
+            );
+            assert_eq!(
+                (-8984199239889394239i64).factors().collect::<Vec<_>>(),
+                vec![-1, 3, 3, 998244353, 1000000007]
+            );
+            assert_eq!(
+                18414358231717282323655036848563253347u128
+                    .factors()
+                    .collect::<Vec<_>>(),
+                vec![998244353, 1000000007, 18446744073709551557]
+            );
+
+            //the minimum of a signed type has no absolute value to take
+            assert_eq!(
+                i64::MIN.factors().collect::<Vec<_>>(),
+                [vec![-1], vec![2; 63]].concat()
+            );
+            assert_eq!(
+                i128::MIN.factors().collect::<Vec<_>>(),
+                [vec![-1], vec![2; 127]].concat()
+            );
+            assert_eq!(
+                (i64::MIN + 1).factors().collect::<Vec<_>>(),
+                vec![-1, 7, 7, 73, 127, 337, 92737, 649657]
+            );
+
+            let x = 2u64 * 2 * 7 * 1021 * 1031 * 65537 * 65537;
+            assert_eq!(
+                PollardRho::factors_of(x).collect::<Vec<_>>(),
+                vec![2, 2, 7, 1021, 1031, 65537, 65537]
+            );
+        }
+    }
 }
@@ -0,0 +1,292 @@
+//!
+//!Enumerating and counting the primes in any [Natural] type
+//!
+//![Primes] iterates over a range of primes using a segmented
+//![Sieve of Eratosthenes](https://en.wikipedia.org/wiki/Sieve_of_Eratosthenes), so only a fixed
+//!amount of memory is needed beyond the primes up to the square root of the upper bound. For finding
+//!single primes near a given number, [next_prime] and [prev_prime] instead test each candidate
+//!with the type's [Primality] impl.
+//!
+//!```
+//!# use traitor::algebra::*;
+//!assert_eq!(Primes::up_to(30u32).collect::<Vec<_>>(), vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29]);
+//!assert_eq!(next_prime(100u64), 101);
+//!assert_eq!(prev_prime(100u64), Some(97));
+//!assert_eq!(nth_prime::<u64>(99), Some(541));
+//!assert_eq!(prime_count(1_000_000u32), 78498);
+//!```
+//!
+
+use crate::algebra::*;
+
+//the number of odd numbers in each segment of the sieve
+const SEGMENT: usize = 1 << 15;
+
+//the odd primes up to and including the given limit using an ordinary sieve
+fn odd_primes(limit: usize) -> Vec<usize> {
+    let mut composite = vec![false; limit / 2 + 1];
+    let mut primes = Vec::new();
+    for i in 1..composite.len() {
+        if !composite[i] {
+            let p = 2 * i + 1;
+            primes.push(p);
+            for j in (p * p / 2..composite.len()).step_by(p) {
+                composite[j] = true;
+            }
+        }
+    }
+    primes
+}
+
+///
+///An iterator over the primes in a range, in increasing order
+///
+///The odd numbers in the range are sieved one segment at a time, with the sieving primes only
+///computed up to the square root of the end of the current segment.
+///
+pub struct Primes<Z: Natural> {
+    low: Z,
+    high: Z,
+    two: bool,
+    last: bool,
+    composite: Vec<bool>,
+    i: usize,
+    base: Vec<usize>,
+    base_limit: usize,
+}
+
+impl<Z: Natural> Primes<Z> {
+    ///All the primes less than or equal to `high`
+    #[inline]
+    pub fn up_to(high: Z) -> Self {
+        Self::between(Z::zero(), high)
+    }
+
+    ///All the primes `p` with `low <= p <= high`
+    pub fn between(low: Z, high: Z) -> Self {
+        let two = low <= Z::two() && Z::two() <= high;
+
+        //the sieve itself only looks at odd numbers from 3 onwards
+        let mut low = low.max(Z::embed_nat(3u8));
+        if low.even() {
+            low += Z::one();
+        }
+
+        Primes {
+            last: low > high,
+            low,
+            high,
+            two,
+            composite: Vec::new(),
+            i: 0,
+            base: Vec::new(),
+            base_limit: 0,
+        }
+    }
+
+    //sieves the segment of odd numbers starting at `low`
+    fn sieve(&mut self) {
+        //the number of odd numbers left, stopping at the last segment
+        let remaining = (self.high.clone() - self.low.clone()).div_two().to_usize();
+        let len = match remaining {
+            Some(n) if n < SEGMENT => {
+                self.last = true;
+                n + 1
+            }
+            _ => SEGMENT,
+        };
+        let top = self.low.clone() + Z::from_usize(2 * (len - 1)).unwrap();
+        let root = top
+            .isqrt()
+            .to_usize()
+            .expect("Attempted to sieve past the square of usize::MAX");
+
+        if root > self.base_limit {
+            self.base_limit = root.max(2 * self.base_limit);
+            self.base = odd_primes(self.base_limit);
+        }
+
+        self.composite.clear();
+        self.composite.resize(len, false);
+        self.i = 0;
+
+        for &p in self.base.iter().take_while(|&&p| p <= root) {
+            let pz = Z::from_usize(p).unwrap();
+
+            //the index of the first odd multiple of p in this segment that isn't p itself
+            let square = pz.clone() * pz.clone();
+            let start = if square >= self.low {
+                (square - self.low.clone()).div_two().to_usize().unwrap()
+            } else {
+                let r = (self.low.clone() % pz).to_usize().unwrap();
+                let delta = if r == 0 { 0 } else { p - r };
+                if delta % 2 == 0 {
+                    delta / 2
+                } else {
+                    (delta + p) / 2
+                }
+            };
+
+            for j in (start..len).step_by(p) {
+                self.composite[j] = true;
+            }
+        }
+    }
+}
+
+impl<Z: Natural> Iterator for Primes<Z> {
+    type Item = Z;
+
+    fn next(&mut self) -> Option<Z> {
+        if self.two {
+            self.two = false;
+            return Some(Z::two());
+        }
+
+        loop {
+            while self.i < self.composite.len() {
+                let i = self.i;
+                self.i += 1;
+                if !self.composite[i] {
+                    return Some(self.low.clone() + Z::from_usize(2 * i).unwrap());
+                }
+            }
+
+            if self.last {
+                return None;
+            }
+            if !self.composite.is_empty() {
+                self.low += Z::from_usize(2 * self.composite.len()).unwrap();
+            }
+            self.sieve();
+        }
+    }
+}
+
+///
+///The smallest prime greater than `n`
+///
+///Panics if the result would overflow `Z`
+///
+pub fn next_prime<Z: Natural>(n: Z) -> Z {
+    if n < Z::two() {
+        return Z::two();
+    }
+
+    let mut p = n + Z::one();
+    if p.even() {
+        p += Z::one();
+    }
+    while !p.prime() {
+        p += Z::two();
+    }
+    p
+}
+
+///The largest prime less than `n`, if there is one
+pub fn prev_prime<Z: Natural>(n: Z) -> Option<Z> {
+    if n <= Z::two() {
+        return None;
+    }
+    if n == Z::embed_nat(3u8) {
+        return Some(Z::two());
+    }
+
+    let mut p = n - Z::one();
+    if p.even() {
+        p -= Z::one();
+    }
+    while !p.prime() {
+        p -= Z::two();
+    }
+    Some(p)
+}
+
+///
+///The `n`th prime counting from zero, so that `nth_prime(0)` is `2`, or `None` if it is too large for `Z`
+///
+pub fn nth_prime<Z: Natural>(n: usize) -> Option<Z> {
+    //the (n+1)th prime is less than m(ln(m) + ln(ln(m))) for m = n+1 >= 6
+    let bound = if n < 5 {
+        11
+    } else {
+        let m = (n + 1) as f64;
+        (m * (m.ln() + m.ln().ln())) as u64
+    };
+
+    //if the bound doesn't fit, the type is narrower than a u64, so we sieve up to its maximum
+    let bound = Z::from_u64(bound)
+        .or_else(|| Z::from_u32(u32::MAX))
+        .or_else(|| Z::from_u16(u16::MAX))
+        .or_else(|| Z::from_u8(u8::MAX))?;
+    Primes::up_to(bound).nth(n)
+}
+
+///The number of primes less than or equal to `x`
+#[inline]
+pub fn prime_count<Z: Natural>(x: Z) -> usize {
+    Primes::up_to(x).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::algebra::*;
+
+    #[test]
+    fn sieve() {
+        let small: Vec<u8> = Primes::up_to(u8::MAX).collect();
+        assert_eq!(small.len(), 54);
+        assert_eq!(small[..6], [2, 3, 5, 7, 11, 13]);
+        assert_eq!(small.last(), Some(&251));
+        assert!(small.iter().all(|p| p.prime()));
+
+        assert_eq!(Primes::up_to(1u32).next(), None);
+        assert_eq!(Primes::up_to(2u32).collect::<Vec<_>>(), vec![2]);
+        assert_eq!(Primes::between(14u32, 16).next(), None);
+        assert_eq!(
+            Primes::between(89u32, 113).collect::<Vec<_>>(),
+            vec![89, 97, 101, 103, 107, 109, 113]
+        );
+
+        //across several segments and far from zero
+        let high: Vec<u64> = Primes::between(1 << 40, (1 << 40) + 300000).collect();
+        assert_eq!(high.first(), Some(&1099511627791));
+        assert!(high.iter().all(|p| p.prime()));
+        let count = ((1u64 << 40)..=(1 << 40) + 300000)
+            .filter(|n| n.prime())
+            .count();
+        assert_eq!(high.len(), count);
+
+        let big: Vec<BigNat> =
+            Primes::between(BigNat::from(1000u32), BigNat::from(1020u32)).collect();
+        assert_eq!(
+            big,
+            vec![
+                BigNat::from(1009u32),
+                BigNat::from(1013u32),
+                BigNat::from(1019u32)
+            ]
+        );
+    }
+
+    #[test]
+    fn counting() {
+        assert_eq!(prime_count(0u32), 0);
+        assert_eq!(prime_count(10u32), 4);
+        assert_eq!(prime_count(u16::MAX), 6542);
+        assert_eq!(prime_count(10_000_000u64), 664579);
+
+        assert_eq!(nth_prime::<u32>(0), Some(2));
+        assert_eq!(nth_prime::<u32>(4), Some(11));
+        assert_eq!(nth_prime::<u32>(9999), Some(104729));
+        assert_eq!(nth_prime::<u8>(53), Some(251));
+        assert_eq!(nth_prime::<u8>(54), None);
+
+        assert_eq!(next_prime(0u8), 2);
+        assert_eq!(next_prime(2u8), 3);
+        assert_eq!(next_prime(u32::MAX as u64), 4294967311);
+        assert_eq!(prev_prime(2u8), None);
+        assert_eq!(prev_prime(3u8), Some(2));
+        assert_eq!(prev_prime(u64::MAX), Some(18446744073709551557));
+    }
+}
@@ -0,0 +1,588 @@
+//!
+//!A concrete [complex number](real::Complex) type over any [Real] representation
+//!
+//!Note that since this type shares its name with the [Complex](real::Complex) trait, it is
+//!not re-exported into [analysis](crate::analysis) and should be imported from this module directly.
+//!
+
+use {
+    crate::{
+        algebra::*,
+        analysis::{
+            real, real::Complex as _, ComplexSubset, InnerProductSpace, Real, RealExponential, Trig,
+        },
+    },
+    core::fmt::{Display, Formatter, Result as FmtResult},
+};
+
+///
+///A complex number `re + im*i` with real and imaginary components in `R`
+///
+///All of the transcendental functions use their principal branches, so that the discontinuities
+///of [ln](Exponential::try_ln), [sqrt](RealExponential::sqrt), and the inverse trig functions
+///lie on the negative real axis (or the relevant rays of the real or imaginary axes).
+///
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub struct Complex<R> {
+    ///The real component
+    pub re: R,
+    ///The imaginary component
+    pub im: R,
+}
+
+#[inline(always)]
+fn two<R: Real>() -> R {
+    R::one().mul_n(2u32)
+}
+
+impl<R: Real> Complex<R> {
+    ///Constructs the complex number `re + im*i`
+    #[inline]
+    pub fn new(re: R, im: R) -> Self {
+        Complex { re, im }
+    }
+
+    ///Constructs the complex number with the given modulus and argument
+    #[inline]
+    pub fn from_polar(r: R, theta: R) -> Self {
+        let (s, c) = theta.sin_cos();
+        Complex::new(r.clone() * c, r * s)
+    }
+
+    ///
+    ///The angle this number makes with the positive real axis
+    ///
+    ///This is the principal argument, and as such, is always between -π and π
+    ///
+    #[inline]
+    pub fn arg(&self) -> R {
+        R::atan2(self.im.clone(), self.re.clone())
+    }
+
+    ///The [modulus](ComplexSubset::modulus) and [argument](Complex::arg) of this number
+    #[inline]
+    pub fn to_polar(self) -> (R, R) {
+        let arg = self.arg();
+        (self.modulus(), arg)
+    }
+
+    //the equivalent of the float_to_option! macro for complex results
+    #[inline]
+    fn finite(self) -> Option<Self> {
+        if self.re.is_finite() && self.im.is_finite() {
+            Some(self)
+        } else {
+            None
+        }
+    }
+}
+
+impl<R: Real> From<R> for Complex<R> {
+    #[inline]
+    fn from(re: R) -> Self {
+        Complex::new(re, R::zero())
+    }
+}
+
+impl<R: Real + Display> Display for Complex<R> {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        if self.im.negative() {
+            write!(f, "{}-{}i", self.re, -self.im.clone())
+        } else {
+            write!(f, "{}+{}i", self.re, self.im)
+        }
+    }
+}
+
+//
+//Unary operations
+//
+
+impl<R: Real> Neg for Complex<R> {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Complex::new(-self.re, -self.im)
+    }
+}
+
+impl<R: Real> Inv for Complex<R> {
+    type Output = Self;
+    #[inline]
+    fn inv(self) -> Self {
+        let m = self.clone().modulus_sqrd().re;
+        Complex::new(self.re / m.clone(), -self.im / m)
+    }
+}
+
+//
+//Binary operations
+//
+
+impl<R: Real> Add for Complex<R> {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Complex::new(self.re + rhs.re, self.im + rhs.im)
+    }
+}
+
+impl<R: Real> Sub for Complex<R> {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Complex::new(self.re - rhs.re, self.im - rhs.im)
+    }
+}
+
+impl<R: Real> Mul for Complex<R> {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        Complex::new(
+            self.re.clone() * rhs.re.clone() - self.im.clone() * rhs.im.clone(),
+            self.re * rhs.im + self.im * rhs.re,
+        )
+    }
+}
+
+impl<R: Real> Div for Complex<R> {
+    type Output = Self;
+    #[inline]
+    fn div(self, rhs: Self) -> Self {
+        let m = rhs.clone().modulus_sqrd().re;
+        Complex::new(
+            (self.re.clone() * rhs.re.clone() + self.im.clone() * rhs.im.clone()) / m.clone(),
+            (self.im * rhs.re - self.re * rhs.im) / m,
+        )
+    }
+}
+
+impl<R: Real> AddAssign for Complex<R> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = self.clone() + rhs;
+    }
+}
+
+impl<R: Real> SubAssign for Complex<R> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = self.clone() - rhs;
+    }
+}
+
+impl<R: Real> MulAssign for Complex<R> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = self.clone() * rhs;
+    }
+}
+
+impl<R: Real> DivAssign for Complex<R> {
+    #[inline]
+    fn div_assign(&mut self, rhs: Self) {
+        *self = self.clone() / rhs;
+    }
+}
+
+//
+//Identities
+//
+
+impl<R: Real> Zero for Complex<R> {
+    #[inline]
+    fn zero() -> Self {
+        Complex::new(R::zero(), R::zero())
+    }
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.re.is_zero() && self.im.is_zero()
+    }
+}
+
+impl<R: Real> One for Complex<R> {
+    #[inline]
+    fn one() -> Self {
+        Complex::new(R::one(), R::zero())
+    }
+    #[inline]
+    fn is_one(&self) -> bool {
+        self.re.is_one() && self.im.is_zero()
+    }
+}
+
+//
+//Algebraic properties
+//
+
+impl<R: Real> AddAssociative for Complex<R> {}
+impl<R: Real> AddCommutative for Complex<R> {}
+impl<R: Real> MulAssociative for Complex<R> {}
+impl<R: Real> MulCommutative for Complex<R> {}
+impl<R: Real> Distributive for Complex<R> {}
+impl<R: Real> NoZeroDivisors for Complex<R> {}
+impl<R: Real> UniquelyFactorizable for Complex<R> {}
+
+impl<R: Real> Divisibility for Complex<R> {
+    #[inline]
+    fn divides(self, rhs: Self) -> bool {
+        !self.is_zero() || rhs.is_zero()
+    }
+    #[inline]
+    fn divide(self, rhs: Self) -> Option<Self> {
+        if rhs.is_zero() {
+            None
+        } else {
+            Some(self / rhs)
+        }
+    }
+    #[inline]
+    fn unit(&self) -> bool {
+        !self.is_zero()
+    }
+    #[inline]
+    fn inverse(self) -> Option<Self> {
+        if self.is_zero() {
+            None
+        } else {
+            Some(self.inv())
+        }
+    }
+}
+
+impl<R: Real> ComplexSubset for Complex<R> {
+    type Real = R;
+    type Natural = R::Natural;
+    type Integer = R::Integer;
+
+    #[inline]
+    fn as_real(self) -> R {
+        self.re
+    }
+    #[inline]
+    fn as_natural(self) -> R::Natural {
+        self.re.as_natural()
+    }
+    #[inline]
+    fn as_integer(self) -> R::Integer {
+        self.re.as_integer()
+    }
+
+    #[inline]
+    fn floor(self) -> Self {
+        Complex::new(self.re.floor(), self.im.floor())
+    }
+    #[inline]
+    fn ceil(self) -> Self {
+        Complex::new(self.re.ceil(), self.im.ceil())
+    }
+    #[inline]
+    fn round(self) -> Self {
+        Complex::new(self.re.round(), self.im.round())
+    }
+    #[inline]
+    fn trunc(self) -> Self {
+        Complex::new(self.re.trunc(), self.im.trunc())
+    }
+    #[inline]
+    fn fract(self) -> Self {
+        Complex::new(self.re.fract(), self.im.fract())
+    }
+
+    #[inline]
+    fn im(self) -> Self {
+        Complex::new(R::zero(), self.im)
+    }
+    #[inline]
+    fn re(self) -> Self {
+        Complex::new(self.re, R::zero())
+    }
+    #[inline]
+    fn conj(self) -> Self {
+        Complex::new(self.re, -self.im)
+    }
+
+    #[inline]
+    fn modulus_sqrd(self) -> Self {
+        Complex::from(self.re.clone() * self.re + self.im.clone() * self.im)
+    }
+    #[inline]
+    fn modulus(self) -> R {
+        self.modulus_sqrd().re.sqrt()
+    }
+}
+
+impl<R: Real> real::Complex for Complex<R> {
+    #[inline]
+    fn i() -> Self {
+        Complex::new(R::zero(), R::one())
+    }
+    #[inline]
+    fn mul_i(self) -> Self {
+        Complex::new(-self.im, self.re)
+    }
+    #[inline]
+    fn div_i(self) -> Self {
+        Complex::new(self.im, -self.re)
+    }
+}
+
+//
+//Transcendental functions
+//
+
+impl<R: Real> Exponential for Complex<R> {
+    #[inline]
+    fn exp(self) -> Self {
+        Complex::from_polar(self.re.exp(), self.im)
+    }
+
+    ///The principal natural logarithm, ie, the one with an imaginary component between -π and π
+    #[inline]
+    fn try_ln(self) -> Option<Self> {
+        if self.is_zero() {
+            None
+        } else {
+            let arg = self.arg();
+            Complex::new(self.modulus().ln(), arg).finite()
+        }
+    }
+}
+
+impl<R: Real> RealExponential for Complex<R> {
+    ///The principal power `exp(ln(self)*power)`, where `0^p = 0` whenever `p` has a positive real part
+    #[inline]
+    fn try_pow(self, power: Self) -> Option<Self> {
+        if self.is_zero() {
+            if power.re.positive() {
+                Some(Self::zero())
+            } else {
+                None
+            }
+        } else {
+            self.try_ln().and_then(|x| (x * power).exp().finite())
+        }
+    }
+
+    ///The principal root `self^(1/index)`
+    #[inline]
+    fn try_root(self, index: Self) -> Option<Self> {
+        index.inverse().and_then(|x| self.try_pow(x))
+    }
+
+    ///The principal square root, computed directly from the components for better precision
+    #[inline]
+    fn sqrt(self) -> Self {
+        if self.is_zero() {
+            return self;
+        }
+        let m = self.clone().modulus();
+        let re = ((m.clone() + self.re.clone()) / two()).sqrt();
+        let im = ((m - self.re) / two()).sqrt();
+        Complex::new(re, if self.im.negative() { -im } else { im })
+    }
+}
+
+impl<R: Real> Trig for Complex<R> {
+    #[inline]
+    fn sin(self) -> Self {
+        let (s, c) = self.re.sin_cos();
+        Complex::new(s * self.im.clone().cosh(), c * self.im.sinh())
+    }
+    #[inline]
+    fn cos(self) -> Self {
+        let (s, c) = self.re.sin_cos();
+        Complex::new(c * self.im.clone().cosh(), -(s * self.im.sinh()))
+    }
+    #[inline]
+    fn tan(self) -> Self {
+        let (s, c) = self.sin_cos();
+        s / c
+    }
+
+    #[inline]
+    fn sinh(self) -> Self {
+        let (s, c) = self.im.sin_cos();
+        Complex::new(self.re.clone().sinh() * c, self.re.cosh() * s)
+    }
+    #[inline]
+    fn cosh(self) -> Self {
+        let (s, c) = self.im.sin_cos();
+        Complex::new(self.re.clone().cosh() * c, self.re.sinh() * s)
+    }
+    #[inline]
+    fn tanh(self) -> Self {
+        self.clone().sinh() / self.cosh()
+    }
+
+    ///The principal arcsine `-i*ln(i*z + √(1-z²))`
+    #[inline]
+    fn try_asin(self) -> Option<Self> {
+        let root = (Self::one() - self.clone() * self.clone()).sqrt();
+        (self.mul_i() + root).try_ln().map(|x| x.div_i())
+    }
+
+    ///The principal arccosine `π/2 - asin(z)`
+    #[inline]
+    fn try_acos(self) -> Option<Self> {
+        self.try_asin().map(|x| Self::frac_pi_2() - x)
+    }
+
+    ///The principal arctangent `i/2*(ln(1-i*z) - ln(1+i*z))`
+    #[inline]
+    fn atan(self) -> Self {
+        let iz = self.mul_i();
+        let l1 = (Self::one() - iz.clone()).ln();
+        let l2 = (Self::one() + iz).ln();
+        (l1 - l2).mul_i() / Self::from(two::<R>())
+    }
+
+    ///Computes `-i*ln((x + i*y)/√(x²+y²))`, which reduces to the usual argument for real inputs
+    #[inline]
+    fn atan2(y: Self, x: Self) -> Self {
+        let r = (x.clone() * x.clone() + y.clone() * y.clone()).sqrt();
+        if r.is_zero() {
+            Self::zero()
+        } else {
+            ((x + y.mul_i()) / r).ln().div_i()
+        }
+    }
+
+    ///The principal inverse hyperbolic sine `ln(z + √(z²+1))`
+    #[inline]
+    fn try_asinh(self) -> Option<Self> {
+        let root = (self.clone() * self.clone() + Self::one()).sqrt();
+        (self + root).try_ln()
+    }
+
+    ///The principal inverse hyperbolic cosine `ln(z + √(z+1)*√(z-1))`
+    #[inline]
+    fn try_acosh(self) -> Option<Self> {
+        let root = (self.clone() + Self::one()).sqrt() * (self.clone() - Self::one()).sqrt();
+        (self + root).try_ln()
+    }
+
+    ///The principal inverse hyperbolic tangent `(ln(1+z) - ln(1-z))/2`
+    #[inline]
+    fn try_atanh(self) -> Option<Self> {
+        let l1 = (Self::one() + self.clone()).try_ln()?;
+        let l2 = (Self::one() - self).try_ln()?;
+        Some((l1 - l2) / Self::from(two::<R>()))
+    }
+
+    #[inline]
+    fn pi() -> Self {
+        Complex::from(R::pi())
+    }
+}
+
+impl<R: Real> InnerProductSpace<Complex<R>> for Complex<R> {
+    ///The standard one dimensional hermitian product `x*̅y`
+    #[inline]
+    fn inner_product(self, rhs: Self) -> Self {
+        self * rhs.conj()
+    }
+
+    #[inline]
+    fn norm_sqrd(self) -> R {
+        self.modulus_sqrd().re
+    }
+
+    #[inline]
+    fn norm(self) -> R {
+        self.modulus()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use {
+        super::Complex,
+        crate::{
+            algebra::*,
+            analysis::{real::Complex as _, *},
+        },
+    };
+
+    type C = Complex<f64>;
+
+    fn c(re: f64, im: f64) -> C {
+        Complex::new(re, im)
+    }
+
+    fn assert_close(x: C, y: C) {
+        assert!((x - y).modulus() < 1e-10, "{:?} != {:?}", x, y);
+    }
+
+    fn field<F: ComplexField>(x: F, y: F) -> F {
+        x * y.clone() / (y.clone() + y)
+    }
+
+    fn hermitian<F: HermitianForm<C, C>>(form: &F, x: C, y: C) -> C {
+        form.product_of(x, y)
+    }
+
+    #[test]
+    fn arithmetic() {
+        let i = <C as crate::analysis::Complex>::i();
+        assert_eq!(i * i, -C::one());
+        assert_eq!(c(1.0, 2.0) * c(3.0, -1.0), c(5.0, 5.0));
+        assert_close(c(5.0, 5.0) / c(3.0, -1.0), c(1.0, 2.0));
+        assert_close(c(3.0, 4.0).inv(), c(0.12, -0.16));
+        assert_eq!(field(c(2.0, 2.0), c(1.0, 1.0)), c(1.0, 1.0));
+        assert_eq!(c(1.0, 2.0).mul_i(), c(-2.0, 1.0));
+        assert_eq!(c(1.0, 2.0).div_i(), c(2.0, -1.0));
+        assert_eq!(C::from(3.0), c(3.0, 0.0));
+        assert_eq!(c(3.0, 4.0).modulus(), 5.0);
+        assert!(C::zero().divides(C::zero()));
+        assert!(!C::zero().divides(i));
+        assert!(i.divides(C::zero()));
+    }
+
+    #[test]
+    fn exponential() {
+        use core::f64::consts::PI;
+
+        assert_close(c(0.0, PI).exp(), c(-1.0, 0.0));
+        assert_close(c(-1.0, 0.0).ln(), c(0.0, PI));
+        assert_close(c(-1.0, -0.0).ln(), c(0.0, -PI));
+        assert_eq!(C::zero().try_ln(), None);
+        assert_close(c(-4.0, 0.0).sqrt(), c(0.0, 2.0));
+        assert_close(c(3.0, -4.0).sqrt(), c(2.0, -1.0));
+        assert_close(c(0.0, 1.0).pow(c(0.0, 1.0)), c((-PI / 2.0).exp(), 0.0));
+        assert_close(c(-8.0, 0.0).root(c(3.0, 0.0)), c(1.0, 3f64.sqrt()));
+        assert_eq!(C::zero().try_pow(c(2.0, 0.0)), Some(C::zero()));
+        assert_eq!(C::zero().try_pow(c(-2.0, 0.0)), None);
+    }
+
+    #[test]
+    fn trig() {
+        let z = c(0.5, -0.25);
+        let (s, co) = z.sin_cos();
+        assert_close(s * s + co * co, C::one());
+        assert_close(z.sin().asin(), z);
+        assert_close(z.cos().acos(), z);
+        assert_close(z.tan().atan(), z);
+        assert_close(z.sinh().asinh(), z);
+        assert_close(z.cosh().acosh(), c(0.5, -0.25));
+        assert_close(z.tanh().atanh(), z);
+        assert_close(
+            C::atan2(c(1.0, 0.0), c(-1.0, 0.0)),
+            c(3.0 * C::frac_pi_4().re, 0.0),
+        );
+        assert_close(c(0.0, 1.0).atanh(), c(0.0, C::frac_pi_4().re));
+        assert_eq!(c(1.0, 0.0).try_atanh(), None);
+    }
+
+    #[test]
+    fn inner_product() {
+        let x = c(1.0, 2.0);
+        let y = c(3.0, -1.0);
+        assert_eq!(x.inner_product(y), c(1.0, 7.0));
+        assert_eq!(x.norm_sqrd(), 5.0);
+        assert_eq!(hermitian(&InnerProductMetric, x, y), c(1.0, 7.0));
+        assert_eq!(hermitian(&InnerProductMetric, y, x), c(1.0, -7.0));
+        assert!(c(0.0, 0.0).orthogonal(x));
+    }
+}
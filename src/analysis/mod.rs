@@ -4,7 +4,8 @@
 //!Traits in this module have been split into three groups:
 //!* ["Ordered"](analysis::ordered) sets and related properties. This includes the
 //! [archimedian property](analysis::ArchimedeanProperty) and ordered algebraic systems.
-//!* Traits for [Real](analysis::Real) and [Complex](analysis::Complex) properties and representations
+//!* Traits for [Real](analysis::Real) and [Complex](analysis::Complex) properties and representations,
+//! along with a concrete [complex number](analysis::complex::Complex) type
 //!* ["Metric"](analysis::metric) properties and functions. This includes
 //! [metrics](analysis::Metric), [norms](analysis::Norm), and [inner-products](analysis::InnerProductSpace)
 //!
@@ -13,6 +14,7 @@
 
 pub use self::{metric::*, ordered::*, real::*};
 
+pub mod complex;
 pub mod metric;
 pub mod ordered;
 pub mod real;
@@ -779,7 +779,7 @@ macro_rules! impl_real {
         impl Real for $f {
             #[inline(always)] fn approx(self) -> f64 {self as f64}
             #[inline(always)] fn repr(f: f64) -> Self {f as $f}
-            #[inline(always)] fn is_finite(&self) -> bool { self.is_finite() }
+            #[inline(always)] fn is_finite(&self) -> bool { $f::is_finite(*self) }
         }
     )*}
 }
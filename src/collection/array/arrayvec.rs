@@ -0,0 +1,52 @@
+use {
+    super::{Array, ArrayMut, GenArray, GenerateError},
+    arrayvec::ArrayVec,
+};
+
+impl<T, const CAP: usize> Array for ArrayVec<T, CAP> {
+    type Element = T;
+
+    #[inline(always)]
+    fn nth(&self, n: usize) -> Option<&T> {
+        self.get(n)
+    }
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T, const CAP: usize> GenArray for ArrayVec<T, CAP> {
+    ///Takes up to the first `CAP` elements of `gen`, since there is no room for any more
+    #[inline(always)]
+    fn try_generate(gen: impl Iterator<Item = Self::Element>) -> Result<Self, GenerateError> {
+        Ok(gen.take(CAP).collect())
+    }
+}
+
+impl<T, const CAP: usize> ArrayMut for ArrayVec<T, CAP> {
+    #[inline(always)]
+    fn nth_mut(&mut self, n: usize) -> Option<&mut T> {
+        self.get_mut(n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {crate::collection::*, arrayvec::ArrayVec};
+
+    #[test]
+    fn array_vec() {
+        let mut v: ArrayVec<i32, 8> = ArrayVec::generate((1..=4).map(|x| x * x));
+        assert_eq!(v[..], [1, 4, 9, 16]);
+        assert_eq!(v.nth(4), None);
+
+        v.for_each(|x| *x -= 1);
+        assert_eq!(v.fold(0, |acc, x| acc + x), 26);
+
+        //only the first `CAP` elements fit
+        let w: ArrayVec<i32, 2> = ArrayVec::generate(1..=4);
+        assert_eq!(w[..], [1, 2]);
+    }
+}
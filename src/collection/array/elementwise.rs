@@ -0,0 +1,299 @@
+//!
+//!Arithmetic operators for arrays
+//!
+//![Elementwise] wraps any array so that the usual operators act element by element, using the
+//![ArrayMath] traits underneath. Two arrays combine position by position, while the primitive
+//!scalars are broadcast to every element.
+//!
+//!```
+//!# use traitor::collection::*;
+//!let a = Elementwise(vec![1.0f64, 2.0, 3.0]);
+//!let b = Elementwise(vec![4.0, 5.0, 6.0]);
+//!assert_eq!(&a + &b, Elementwise(vec![5.0, 7.0, 9.0]));
+//!assert_eq!(-(&a * 2.0f64), Elementwise(vec![-2.0, -4.0, -6.0]));
+//!
+//!let mut c = b / a;
+//!c += 1.0;
+//!assert_eq!(c.into_inner(), vec![5.0, 3.5, 3.0]);
+//!```
+//!
+
+use {
+    super::{
+        Array, ArrayAdd, ArrayDiv, ArrayInv, ArrayMul, ArrayMut, ArrayNeg, ArraySub, GenArray,
+        GenerateError,
+    },
+    crate::ops::{self, *},
+    core::ops::{Deref, DerefMut},
+    traitor_macros::traitor_ops,
+};
+
+///
+///An array whose arithmetic operators act on each element
+///
+///Binary operators between two arrays panic if their lengths differ. With a primitive scalar on
+///either side, the scalar is combined with every element instead.
+///
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Elementwise<A>(pub A);
+
+impl<A> Elementwise<A> {
+    ///The wrapped array
+    #[inline(always)]
+    pub fn into_inner(self) -> A {
+        self.0
+    }
+}
+
+impl<A> From<A> for Elementwise<A> {
+    #[inline(always)]
+    fn from(array: A) -> Self {
+        Elementwise(array)
+    }
+}
+
+impl<A> Deref for Elementwise<A> {
+    type Target = A;
+
+    #[inline(always)]
+    fn deref(&self) -> &A {
+        &self.0
+    }
+}
+
+impl<A> DerefMut for Elementwise<A> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut A {
+        &mut self.0
+    }
+}
+
+impl<A: Array> Array for Elementwise<A> {
+    type Element = A::Element;
+
+    #[inline(always)]
+    fn nth(&self, n: usize) -> Option<&A::Element> {
+        self.0.nth(n)
+    }
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl<A: ArrayMut> ArrayMut for Elementwise<A> {
+    #[inline(always)]
+    fn nth_mut(&mut self, n: usize) -> Option<&mut A::Element> {
+        self.0.nth_mut(n)
+    }
+}
+
+impl<A: GenArray> GenArray for Elementwise<A> {
+    #[inline(always)]
+    fn try_generate(gen: impl Iterator<Item = A::Element>) -> Result<Self, GenerateError> {
+        A::try_generate(gen).map(Elementwise)
+    }
+}
+
+#[inline(always)]
+fn check_len<A: Array>(x: &A, y: &A) {
+    if x.len() != y.len() {
+        panic!("Attempted to combine arrays of different lengths")
+    }
+}
+
+//replaces each element of `x` with `f` of it and the matching element of `y`
+#[inline(always)]
+fn assign_with<A: ArrayMut>(x: &mut A, y: &A, f: impl Fn(&A::Element, &A::Element) -> A::Element) {
+    check_len(x, y);
+    for (i, b) in y.iter().enumerate() {
+        let a = x.nth_mut(i).unwrap();
+        *a = f(a, b);
+    }
+}
+
+macro_rules! impl_binary {
+    ($($tr:ident $f:ident $assign:ident $f_assign:ident $array:ident $array_f:ident;)*) => {
+        $(
+            impl<A: $array> ops::$tr for Elementwise<A> {
+                type Output = Self;
+
+                #[inline(always)]
+                fn $f(self, rhs: Self) -> Self {
+                    check_len(&self.0, &rhs.0);
+                    Elementwise(self.0.$array_f(&rhs.0))
+                }
+            }
+
+            impl<'a, A: $array> ops::$tr<&'a Elementwise<A>> for &'a Elementwise<A> {
+                type Output = Elementwise<A>;
+
+                #[inline(always)]
+                fn $f(self, rhs: Self) -> Elementwise<A> {
+                    check_len(&self.0, &rhs.0);
+                    Elementwise(self.0.$array_f(&rhs.0))
+                }
+            }
+
+            impl<A: $array> ops::$assign for Elementwise<A> {
+                #[inline(always)]
+                fn $f_assign(&mut self, rhs: Self) {
+                    assign_with(&mut self.0, &rhs.0, |x, y| x.$f(y))
+                }
+            }
+
+            impl<'a, A: $array> ops::$assign<&'a Elementwise<A>> for Elementwise<A> {
+                #[inline(always)]
+                fn $f_assign(&mut self, rhs: &'a Elementwise<A>) {
+                    assign_with(&mut self.0, &rhs.0, |x, y| x.$f(y))
+                }
+            }
+        )*
+    };
+}
+
+impl_binary! {
+    Add add AddAssign add_assign ArrayAdd array_add;
+    Sub sub SubAssign sub_assign ArraySub array_sub;
+    Mul mul MulAssign mul_assign ArrayMul array_mul;
+    Div div DivAssign div_assign ArrayDiv array_div;
+}
+
+macro_rules! impl_unary {
+    ($($tr:ident $f:ident $array:ident $array_f:ident;)*) => {
+        $(
+            impl<A: $array> ops::$tr for Elementwise<A> {
+                type Output = Self;
+
+                #[inline(always)]
+                fn $f(self) -> Self {
+                    Elementwise(self.0.$array_f())
+                }
+            }
+
+            impl<'a, A: $array> ops::$tr for &'a Elementwise<A> {
+                type Output = Elementwise<A>;
+
+                #[inline(always)]
+                fn $f(self) -> Elementwise<A> {
+                    Elementwise(self.0.$array_f())
+                }
+            }
+        )*
+    };
+}
+
+impl_unary! {
+    Neg neg ArrayNeg array_neg;
+    Inv inv ArrayInv array_inv;
+}
+
+traitor_ops!(Elementwise<A>);
+
+//broadcasting a primitive scalar to every element, from either side
+macro_rules! impl_scalar {
+    ($($s:ty)*) => {
+        $(
+            impl_scalar!(@op $s, Add add AddAssign add_assign);
+            impl_scalar!(@op $s, Sub sub SubAssign sub_assign);
+            impl_scalar!(@op $s, Mul mul MulAssign mul_assign);
+            impl_scalar!(@op $s, Div div DivAssign div_assign);
+        )*
+    };
+    (@op $s:ty, $tr:ident $f:ident $assign:ident $f_assign:ident) => {
+        impl<A: ArrayMut<Element = $s>> core::ops::$tr<$s> for Elementwise<A> {
+            type Output = Self;
+
+            #[inline(always)]
+            fn $f(mut self, k: $s) -> Self {
+                self.0.for_each(|x| *x = core::ops::$tr::$f(*x, k));
+                self
+            }
+        }
+
+        impl<'a, A: GenArray<Element = $s>> core::ops::$tr<$s> for &'a Elementwise<A> {
+            type Output = Elementwise<A>;
+
+            #[inline(always)]
+            fn $f(self, k: $s) -> Elementwise<A> {
+                Elementwise(self.0.map(|&x| core::ops::$tr::$f(x, k)))
+            }
+        }
+
+        impl<A: ArrayMut<Element = $s>> core::ops::$assign<$s> for Elementwise<A> {
+            #[inline(always)]
+            fn $f_assign(&mut self, k: $s) {
+                self.0.for_each(|x| *x = core::ops::$tr::$f(*x, k))
+            }
+        }
+
+        impl<A: ArrayMut<Element = $s>> core::ops::$tr<Elementwise<A>> for $s {
+            type Output = Elementwise<A>;
+
+            #[inline(always)]
+            fn $f(self, mut a: Elementwise<A>) -> Elementwise<A> {
+                a.0.for_each(|x| *x = core::ops::$tr::$f(self, *x));
+                a
+            }
+        }
+
+        impl<'a, A: GenArray<Element = $s>> core::ops::$tr<&'a Elementwise<A>> for $s {
+            type Output = Elementwise<A>;
+
+            #[inline(always)]
+            fn $f(self, a: &'a Elementwise<A>) -> Elementwise<A> {
+                Elementwise(a.0.map(|&x| core::ops::$tr::$f(self, x)))
+            }
+        }
+    };
+}
+
+impl_scalar! { usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 f32 f64 }
+
+#[cfg(test)]
+mod tests {
+    use {crate::collection::*, num_traits::Inv};
+
+    #[test]
+    fn arrays() {
+        let a = Elementwise(vec![1.0, 2.0, 4.0]);
+        let b = Elementwise(vec![2.0, 2.0, 2.0]);
+        assert_eq!(&a + &b, Elementwise(vec![3.0, 4.0, 6.0]));
+        assert_eq!(&a - &b, Elementwise(vec![-1.0, 0.0, 2.0]));
+        assert_eq!(&a * &b, Elementwise(vec![2.0, 4.0, 8.0]));
+        assert_eq!(&a / &b, Elementwise(vec![0.5, 1.0, 2.0]));
+        assert_eq!(-&a, Elementwise(vec![-1.0, -2.0, -4.0]));
+        assert_eq!((&a).inv(), Elementwise(vec![1.0, 0.5, 0.25]));
+        assert_eq!(a.clone() + b.clone(), &a + &b);
+
+        let mut c = Elementwise([1.0f32, 2.0, 3.0]);
+        c += Elementwise([1.0, 1.0, 1.0]);
+        c *= &Elementwise([2.0, 0.5, -1.0]);
+        assert_eq!(c, Elementwise([4.0, 1.5, -4.0]));
+        c -= c;
+        assert_eq!(c.into_inner(), [0.0; 3]);
+    }
+
+    #[test]
+    fn scalars() {
+        let a = Elementwise([1, 2, 3]);
+        assert_eq!(a + 1, Elementwise([2, 3, 4]));
+        assert_eq!(a * 3, Elementwise([3, 6, 9]));
+        assert_eq!(10 - a, Elementwise([9, 8, 7]));
+        assert_eq!(6 / &a, Elementwise([6, 3, 2]));
+        assert_eq!(&a - 1, Elementwise([0, 1, 2]));
+
+        let mut v = Elementwise(vec![1.0, 2.0]);
+        v /= 4.0;
+        v -= 1.0;
+        assert_eq!(v.into_inner(), vec![-0.75, -0.5]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn different_lengths() {
+        let _ = Elementwise(vec![1, 2]) + Elementwise(vec![1, 2, 3]);
+    }
+}
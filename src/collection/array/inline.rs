@@ -1,6 +1,6 @@
 use {
-    super::{Array, ArrayMut, GenArray, StaticLenArray},
-    core::mem::MaybeUninit,
+    super::{Array, ArrayMut, GenArray, GenerateError, StaticLenArray},
+    core::{mem::MaybeUninit, ptr},
     num_traits::ToPrimitive,
 };
 
@@ -26,8 +26,8 @@ where
     [T; L]: Sized,
 {
     #[inline(always)]
-    fn generate(gen: impl Iterator<Item = T>) -> Self {
-        <Self as StaticLenArray>::generate(gen)
+    fn try_generate(gen: impl Iterator<Item = T>) -> Result<Self, GenerateError> {
+        <Self as StaticLenArray>::try_generate(gen)
     }
 }
 
@@ -67,6 +67,21 @@ where
     }
 }
 
+//the slots of an array being filled in order, dropping the ones written so far if it never completes
+struct PartialArray<T, const L: usize> {
+    slots: [MaybeUninit<T>; L],
+    init: usize,
+}
+
+impl<T, const L: usize> Drop for PartialArray<T, L> {
+    fn drop(&mut self) {
+        for slot in &mut self.slots[..self.init] {
+            //safety: the first `init` slots have been written and not moved out of
+            unsafe { slot.assume_init_drop() }
+        }
+    }
+}
+
 impl<T, const L: usize> StaticLenArray for [T; L]
 where
     [T; L]: Sized,
@@ -78,14 +93,69 @@ where
         L
     }
 
-    #[inline(always)]
-    fn generate(gen: impl Iterator<Item = T>) -> Self {
-        let mut array: [T; L] = unsafe { MaybeUninit::uninit().assume_init() };
+    fn try_generate(gen: impl Iterator<Item = T>) -> Result<Self, GenerateError> {
+        let mut partial = PartialArray {
+            slots: [const { MaybeUninit::uninit() }; L],
+            init: 0,
+        };
+
+        //if the iterator panics, the guard drops whatever it has written
+        for element in gen.take(L) {
+            partial.slots[partial.init].write(element);
+            partial.init += 1;
+        }
 
-        for (i, element) in gen.take(L).enumerate() {
-            array[i] = element;
+        if partial.init < L {
+            return Err(GenerateError {
+                expected: L,
+                found: partial.init,
+            });
         }
 
-        array
+        //safety: every slot is initialized, and the guard is disarmed so nothing is dropped twice
+        partial.init = 0;
+        Ok(unsafe { ptr::read(partial.slots.as_ptr() as *const [T; L]) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {crate::collection::*, std::rc::Rc};
+
+    #[test]
+    fn generate() {
+        assert_eq!(<[u32; 4] as StaticLenArray>::generate(1..), [1, 2, 3, 4]);
+        assert_eq!(<[u32; 0] as GenArray>::try_generate(0..0), Ok([]));
+        assert_eq!(
+            <[u32; 4] as GenArray>::try_generate(1..3),
+            Err(GenerateError {
+                expected: 4,
+                found: 2
+            })
+        );
+        assert_eq!(
+            [1, 2, 3].component_wise(&[4, 5, 6], |x, y| x * y),
+            [4, 10, 18]
+        );
+    }
+
+    #[test]
+    fn generate_drops() {
+        //a short iterator drops the elements it did yield exactly once
+        let rc = Rc::new(());
+        let short = <[Rc<()>; 8] as GenArray>::try_generate(std::iter::repeat_n(rc.clone(), 5));
+        assert!(short.is_err());
+        assert_eq!(Rc::strong_count(&rc), 1);
+
+        let full = <[Rc<()>; 8] as GenArray>::try_generate(std::iter::repeat(rc.clone())).unwrap();
+        assert_eq!(Rc::strong_count(&rc), 9);
+        drop(full);
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn generate_short() {
+        <[u8; 3] as GenArray>::generate(0..2);
     }
 }
@@ -1,7 +1,7 @@
 use {
     crate::{
         analysis::{Metric, Real, RealExponential},
-        collection::{Array, ArraySub, RealArray},
+        collection::{Array, RealArray, RefMath},
         ops::*,
     },
     num_traits::Zero,
@@ -10,7 +10,7 @@ use {
 // Haven't figured out yet how to use InnerProductMetric to impl this...
 pub struct EuclideanMetric;
 
-impl<'a, X: ArraySub + RealArray> Metric<&'a X, X::Element> for EuclideanMetric {
+impl<'a, X: Array<Element: Real + RefMath>> Metric<&'a X, X::Element> for EuclideanMetric {
     fn distance(&self, x1: &'a X, x2: &'a X) -> X::Element {
         x1.zip_fold(x2, X::Element::repr(0.0), |a, (x, y)| {
             a.add(&(x.sub(y)).pow(X::Element::repr(2.0)))
@@ -29,7 +29,9 @@ impl<W: RealArray> WeightedEuclideanMetric<W> {
     }
 }
 
-impl<'a, X: RealArray, W: RealArray> Metric<&'a X, X::Element> for WeightedEuclideanMetric<W> {
+impl<'a, X: Array<Element: Real + RefMath>, W: RealArray> Metric<&'a X, X::Element>
+    for WeightedEuclideanMetric<W>
+{
     fn distance(&self, x1: &'a X, x2: &'a X) -> X::Element {
         let mut acc = X::Element::zero();
 
@@ -3,7 +3,7 @@ use {
         analysis::{Metric, Real, RealExponential},
         ops::*,
     },
-    core::{iter, mem::MaybeUninit},
+    core::{iter, mem::MaybeUninit, ops::RangeBounds},
     num_traits::ToPrimitive,
     replace_with::replace_with_or_abort,
     traitor_macros::auto_gen_impl,
@@ -21,6 +21,16 @@ pub use vec::*;
 mod smallvec;
 pub use smallvec::*;
 
+mod arrayvec;
+
+mod slice;
+
+mod view;
+pub use view::*;
+
+mod elementwise;
+pub use elementwise::*;
+
 /// An array is a thing that permits random access at integer offsets.
 pub trait Array: Sized {
     type Element;
@@ -59,11 +69,61 @@ pub trait Array: Sized {
             array: self,
         }
     }
+
+    /// A view of the elements in `range`, panicking if it is out of bounds
+    #[inline(always)]
+    fn slice(&self, range: impl RangeBounds<usize>) -> ArraySlice<&Self> {
+        ArraySlice::new(self, range)
+    }
+
+    /// A view of every `step`th element, panicking if `step` is zero
+    #[inline(always)]
+    fn strided(&self, step: usize) -> Strided<&Self> {
+        Strided::new(self, step)
+    }
+
+    /// A view of the elements in reverse order
+    #[inline(always)]
+    fn reversed(&self) -> Reversed<&Self> {
+        Reversed::new(self)
+    }
+}
+
+///The error returned when an iterator runs out before a fixed length array is filled
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct GenerateError {
+    ///The number of elements the array needed
+    pub expected: usize,
+    ///The number of elements the iterator actually yielded
+    pub found: usize,
+}
+
+impl core::fmt::Display for GenerateError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(
+            f,
+            "expected {} elements but the iterator only yielded {}",
+            self.expected, self.found
+        )
+    }
 }
 
 // an array that generates itself
 pub trait GenArray: Array + ArrayMut {
-    fn generate(gen: impl Iterator<Item = Self::Element>) -> Self;
+    ///Builds an array from the elements of `gen`, failing if there are too few to fill it
+    fn try_generate(gen: impl Iterator<Item = Self::Element>) -> Result<Self, GenerateError>;
+
+    ///Builds an array from the elements of `gen`, panicking if there are too few to fill it
+    #[inline(always)]
+    fn generate(gen: impl Iterator<Item = Self::Element>) -> Self {
+        match Self::try_generate(gen) {
+            Ok(array) => array,
+            Err(e) => panic!(
+                "Attempted to generate an array from too few elements: {}",
+                e
+            ),
+        }
+    }
 
     #[inline(always)]
     fn component_wise(
@@ -114,6 +174,73 @@ pub trait ArrayMut: Array {
         self.iter_mut()
             .for_each(|x| replace_with_or_abort(x, |v| f(v)))
     }
+
+    /// Adds `offset` to every element in place
+    fn offset_inplace(&mut self, offset: &Self::Element)
+    where
+        Self::Element: RefAdd,
+    {
+        self.for_each(|x| *x = (&*x).add(offset))
+    }
+
+    /// Multiplies every element by `factor` in place
+    fn scale_inplace(&mut self, factor: &Self::Element)
+    where
+        Self::Element: RefMul,
+    {
+        self.for_each(|x| *x = (&*x).mul(factor))
+    }
+
+    /// Divides every element by `divisor` in place
+    fn div_scalar_inplace(&mut self, divisor: &Self::Element)
+    where
+        Self::Element: RefDiv,
+    {
+        self.for_each(|x| *x = (&*x).div(divisor))
+    }
+
+    /// Restricts every element to the interval `[min, max]` in place, panicking if `min > max`
+    fn clamp_elements_inplace(&mut self, min: Self::Element, max: Self::Element)
+    where
+        Self::Element: PartialOrd + Copy,
+    {
+        if min > max {
+            panic!("Attempted to clamp to an empty interval")
+        }
+        self.for_each(|x| {
+            if *x < min {
+                *x = min
+            } else if *x > max {
+                *x = max
+            }
+        })
+    }
+
+    /// Raises every element to the power `power` in place
+    fn pow_scalar_inplace(&mut self, power: &Self::Element)
+    where
+        Self::Element: Real,
+    {
+        self.map_inplace(|x| x.pow(power.clone()))
+    }
+
+    /// A mutable view of the elements in `range`, panicking if it is out of bounds
+    #[inline(always)]
+    fn slice_mut(&mut self, range: impl RangeBounds<usize>) -> ArraySlice<&mut Self> {
+        ArraySlice::new(self, range)
+    }
+
+    /// A mutable view of every `step`th element, panicking if `step` is zero
+    #[inline(always)]
+    fn strided_mut(&mut self, step: usize) -> Strided<&mut Self> {
+        Strided::new(self, step)
+    }
+
+    /// A mutable view of the elements in reverse order
+    #[inline(always)]
+    fn reversed_mut(&mut self) -> Reversed<&mut Self> {
+        Reversed::new(self)
+    }
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -152,11 +279,25 @@ impl<'a, A: 'a + ArrayMut> Iterator for ArrayMutIter<'a, A> {
     }
 }
 
-pub trait StaticLenArray {
+pub trait StaticLenArray: Sized {
     type Element;
 
     fn len() -> usize;
-    fn generate(gen: impl Iterator<Item = Self::Element>) -> Self;
+
+    ///Takes the first [len](StaticLenArray::len) elements of `gen`, failing if there are fewer
+    fn try_generate(gen: impl Iterator<Item = Self::Element>) -> Result<Self, GenerateError>;
+
+    ///Takes the first [len](StaticLenArray::len) elements of `gen`, panicking if there are fewer
+    #[inline(always)]
+    fn generate(gen: impl Iterator<Item = Self::Element>) -> Self {
+        match Self::try_generate(gen) {
+            Ok(array) => array,
+            Err(e) => panic!(
+                "Attempted to generate an array from too few elements: {}",
+                e
+            ),
+        }
+    }
 }
 
 #[auto_gen_impl(OrdArrayConstraint)]
@@ -182,6 +323,13 @@ pub trait OrdArray: GenArray + Array<Element: PartialOrd + Copy> {
                 .map(|(x, y)| if x.gt(y) { *x } else { *y }),
         )
     }
+
+    /// Restricts every element to the interval `[min, max]`, panicking if `min > max`
+    fn clamp_elements(&self, min: Self::Element, max: Self::Element) -> Self {
+        let mut clamped = self.map(|&x| x);
+        clamped.clamp_elements_inplace(min, max);
+        clamped
+    }
 }
 
 #[auto_gen_impl(ArraySubConstraint)]
@@ -199,10 +347,11 @@ pub trait ArrayAdd: Array<Element: RefAdd> + GenArray {
         self.component_wise(other, |x, y| x.add(y))
     }
 
-    // #[inline(always)]
-    // fn offset(self, offset: Self::Element) -> Self::GenOut {
-    //     self.map(|x| x.add(offset))
-    // }
+    /// Adds `offset` to every element
+    #[inline(always)]
+    fn offset(&self, offset: &Self::Element) -> Self {
+        self.map(|x| x.add(offset))
+    }
 }
 
 #[auto_gen_impl(ArrayMulConstraint)]
@@ -212,10 +361,11 @@ pub trait ArrayMul: Array<Element: RefMul> + GenArray {
         self.component_wise(other, |x, y| x.mul(y))
     }
 
-    // #[inline(always)]
-    // fn scale(&self, offset: &Self::Element) -> Self::GenOut {
-    //     self.map(|x| x * offset)
-    // }
+    /// Multiplies every element by `factor`
+    #[inline(always)]
+    fn scale(&self, factor: &Self::Element) -> Self {
+        self.map(|x| x.mul(factor))
+    }
 }
 
 #[auto_gen_impl(ArrayDivConstraint)]
@@ -224,6 +374,12 @@ pub trait ArrayDiv: Array<Element: RefDiv> + GenArray {
     fn array_div(&self, other: &Self) -> Self {
         self.component_wise(other, |x, y| x.div(y))
     }
+
+    /// Divides every element by `divisor`
+    #[inline(always)]
+    fn div_scalar(&self, divisor: &Self::Element) -> Self {
+        self.map(|x| x.div(divisor))
+    }
 }
 
 #[auto_gen_impl(ArrayPartialEqConstraint)]
@@ -272,35 +428,26 @@ impl<'a, A: Array> Array for &'a A {
     // }
 }
 
-// impl<'a, A: Array> Array for &'a mut A {
-//     type GenOut = A::GenOut;
-//     type Element = A::Element;
-
-//     #[inline(always)]
-//     fn nth(&self, n: usize) -> Option<&Self::Element> {
-//         (**self).nth(n)
-//     }
+impl<'a, A: Array> Array for &'a mut A {
+    type Element = A::Element;
 
-//     #[inline(always)]
-//     fn len(&self) -> usize {
-//         (**self).len()
-//     }
+    #[inline(always)]
+    fn nth(&self, n: usize) -> Option<&Self::Element> {
+        (**self).nth(n)
+    }
 
-//     // #[inline(always)]
-//     // fn generate(
-//     //     len: usize,
-//     //     gen: impl Iterator<Item = <Self::GenOut as Array>::Element>,
-//     // ) -> Self::GenOut {
-//     //     A::generate(len, gen)
-//     // }
-// }
+    #[inline(always)]
+    fn len(&self) -> usize {
+        (**self).len()
+    }
+}
 
-// impl<'a, A: ArrayMut> ArrayMut for &'a mut A {
-//     #[inline(always)]
-//     fn nth_mut(&mut self, n: usize) -> Option<&mut Self::Element> {
-//         (*self).nth_mut(n)
-//     }
-// }
+impl<'a, A: ArrayMut> ArrayMut for &'a mut A {
+    #[inline(always)]
+    fn nth_mut(&mut self, n: usize) -> Option<&mut Self::Element> {
+        (**self).nth_mut(n)
+    }
+}
 
 // impl<A> PartialEq for Bound<A>
 // where
@@ -311,116 +458,6 @@ impl<'a, A: Array> Array for &'a A {
 //     }
 // }
 
-// impl<'a, A> crate::ops::Add for &'a A
-// where
-//     A: ArrayAdd,
-// {
-//     type Output = A::GenOut;
-
-//     fn add(self, rhs: Self) -> Self::Output {
-//         self.add(&rhs)
-//     }
-// }
-
-// impl<'a, A> crate::ops::Sub for &'a A
-// where
-//     A: ArraySub,
-// {
-//     type Output = A::GenOut;
-
-//     fn sub(self, rhs: Self) -> Self::Output {
-//         self.sub(&rhs)
-//     }
-// }
-
-// impl<'a, A> crate::ops::Neg for &'a A
-// where
-//     A: ArrayNeg,
-// {
-//     type Output = A::GenOut;
-
-//     fn neg(self) -> Self::Output {
-//         self.neg()
-//     }
-// }
-
-// impl<'a, A> crate::ops::Mul for &'a A
-// where
-//     A: ArrayMul,
-// {
-//     type Output = A::GenOut;
-
-//     fn mul(self, rhs: Self) -> Self::Output {
-//         self.mul(&rhs)
-//     }
-// }
-
-// impl<'a, A> crate::ops::Div for &'a A
-// where
-//     A: ArrayDiv,
-// {
-//     type Output = A::GenOut;
-
-//     fn div(self, rhs: Self) -> Self::Output {
-//         self.div(&rhs)
-//     }
-// }
-
-// impl<A> crate::ops::Add for A
-// where
-//     A: ArrayAdd,
-// {
-//     type Output = A;
-
-//     fn add(self, rhs: Self) -> Self::Output {
-//         self.array_add(&rhs)
-//     }
-// }
-
-// impl<A> crate::ops::Sub for A
-// where
-//     A: ArraySub,
-// {
-//     type Output = A;
-
-//     fn sub(self, rhs: Self) -> Self::Output {
-//         self.array_sub(&rhs)
-//     }
-// }
-
-// impl<A> crate::ops::Neg for A
-// where
-//     A: ArrayNeg,
-// {
-//     type Output = A;
-
-//     fn neg(self) -> Self::Output {
-//         self.array_neg()
-//     }
-// }
-
-// impl<A> crate::ops::Mul for A
-// where
-//     A: ArrayMul,
-// {
-//     type Output = A;
-
-//     fn mul(self, rhs: Self) -> Self::Output {
-//         self.array_mul(&rhs)
-//     }
-// }
-
-// impl<A> crate::ops::Div for A
-// where
-//     A: ArrayDiv,
-// {
-//     type Output = A;
-
-//     fn div(self, rhs: Self) -> Self::Output {
-//         self.array_div(&rhs)
-//     }
-// }
-
 pub trait RefMath = RefAdd + RefSub + RefNeg + RefMul + RefDiv + RefInv;
 pub trait ArrayMath = ArrayAdd + ArraySub + ArrayNeg + ArrayMul + ArrayDiv + ArrayInv;
 
@@ -430,4 +467,51 @@ pub trait RealArray: Array<Element: Real + RefMath> + OrdArray + ArrayMath + Clo
     fn repr(value: f64) -> Self::Element {
         Self::Element::repr(value)
     }
+
+    /// Raises every element to the power `power`
+    #[inline(always)]
+    fn pow_scalar(&self, power: &Self::Element) -> Self {
+        self.map(|&x| x.pow(*power))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::collection::*;
+
+    #[test]
+    fn broadcasting() {
+        let v = vec![1.0, -2.0, 4.0];
+        assert_eq!(v.offset(&1.0), vec![2.0, -1.0, 5.0]);
+        assert_eq!(v.scale(&3.0), vec![3.0, -6.0, 12.0]);
+        assert_eq!(v.div_scalar(&2.0), vec![0.5, -1.0, 2.0]);
+        assert_eq!(v.clamp_elements(-1.0, 2.0), vec![1.0, -1.0, 2.0]);
+        assert_eq!(vec![1, 5, 9].clamp_elements(2, 6), vec![2, 5, 6]);
+        assert_eq!(v.pow_scalar(&2.0), vec![1.0, 4.0, 16.0]);
+
+        let a = [1.0f32, 4.0, 9.0];
+        assert_eq!(a.pow_scalar(&0.5), [1.0, 2.0, 3.0]);
+        assert_eq!(a.scale(&2.0).offset(&-1.0), [1.0, 7.0, 17.0]);
+
+        //the in-place variants also work through views
+        let mut w = vec![1, 2, 3, 4, 5, 6];
+        w.slice_mut(3..).offset_inplace(&10);
+        assert_eq!(w, vec![1, 2, 3, 14, 15, 16]);
+        w.strided_mut(2).scale_inplace(&-1);
+        assert_eq!(w, vec![-1, 2, -3, 14, -15, 16]);
+        w.div_scalar_inplace(&2);
+        assert_eq!(w, vec![0, 1, -1, 7, -7, 8]);
+        w.clamp_elements_inplace(-2, 2);
+        assert_eq!(w, vec![0, 1, -1, 2, -2, 2]);
+
+        let mut x = [2.0, 3.0];
+        x.pow_scalar_inplace(&3.0);
+        assert_eq!(x, [8.0, 27.0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn empty_clamp() {
+        [1.0, 2.0].clamp_elements_inplace(1.0, 0.0);
+    }
 }
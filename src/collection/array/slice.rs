@@ -0,0 +1,150 @@
+#[cfg(feature = "std")]
+use {
+    super::{GenArray, GenerateError},
+    std::borrow::Cow,
+};
+
+use super::{Array, ArrayMut};
+
+impl<T> Array for &[T] {
+    type Element = T;
+
+    #[inline(always)]
+    fn nth(&self, n: usize) -> Option<&T> {
+        self.get(n)
+    }
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        <[T]>::len(self)
+    }
+}
+
+impl<T> Array for &mut [T] {
+    type Element = T;
+
+    #[inline(always)]
+    fn nth(&self, n: usize) -> Option<&T> {
+        self.get(n)
+    }
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        <[T]>::len(self)
+    }
+}
+
+impl<T> ArrayMut for &mut [T] {
+    #[inline(always)]
+    fn nth_mut(&mut self, n: usize) -> Option<&mut T> {
+        self.get_mut(n)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> Array for Box<[T]> {
+    type Element = T;
+
+    #[inline(always)]
+    fn nth(&self, n: usize) -> Option<&T> {
+        self.get(n)
+    }
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        <[T]>::len(self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> GenArray for Box<[T]> {
+    #[inline(always)]
+    fn try_generate(gen: impl Iterator<Item = Self::Element>) -> Result<Self, GenerateError> {
+        Ok(gen.collect())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> ArrayMut for Box<[T]> {
+    #[inline(always)]
+    fn nth_mut(&mut self, n: usize) -> Option<&mut T> {
+        self.get_mut(n)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a, T: Clone> Array for Cow<'a, [T]> {
+    type Element = T;
+
+    #[inline(always)]
+    fn nth(&self, n: usize) -> Option<&T> {
+        self.get(n)
+    }
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        <[T]>::len(self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a, T: Clone> GenArray for Cow<'a, [T]> {
+    #[inline(always)]
+    fn try_generate(gen: impl Iterator<Item = Self::Element>) -> Result<Self, GenerateError> {
+        Ok(Cow::Owned(gen.collect()))
+    }
+}
+
+#[cfg(feature = "std")]
+///Mutating a borrowed slice clones it first
+impl<'a, T: Clone> ArrayMut for Cow<'a, [T]> {
+    #[inline(always)]
+    fn nth_mut(&mut self, n: usize) -> Option<&mut T> {
+        if n < <[T]>::len(self) {
+            self.to_mut().get_mut(n)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {crate::collection::*, std::borrow::Cow};
+
+    fn sum<A: Array<Element = i32>>(a: &A) -> i32 {
+        a.fold(0, |acc, x| acc + x)
+    }
+
+    fn double<A: ArrayMut<Element = i32>>(mut a: A) -> A {
+        a.for_each(|x| *x *= 2);
+        a
+    }
+
+    fn squares<A: GenArray<Element = i32>>() -> A {
+        A::generate((1..=4).map(|x| x * x))
+    }
+
+    #[test]
+    fn containers() {
+        let v = vec![1, 2, 3, 4];
+        assert_eq!(sum(&v.as_slice()), 10);
+        assert_eq!(sum(&v.clone().into_boxed_slice()), 10);
+        assert_eq!(sum(&Cow::Borrowed(&v[..])), 10);
+
+        let mut w = v.clone();
+        double(w.as_mut_slice());
+        assert_eq!(w, vec![2, 4, 6, 8]);
+        double(&mut w);
+        assert_eq!(w, vec![4, 8, 12, 16]);
+
+        //mutating a borrowed cow leaves the original alone
+        let cow = double(Cow::Borrowed(&v[..]));
+        assert!(matches!(cow, Cow::Owned(_)));
+        assert_eq!(cow[..], [2, 4, 6, 8]);
+        assert_eq!(v, vec![1, 2, 3, 4]);
+
+        assert_eq!(squares::<Box<[i32]>>()[..], [1, 4, 9, 16]);
+        assert_eq!(squares::<Cow<[i32]>>()[..], [1, 4, 9, 16]);
+    }
+}
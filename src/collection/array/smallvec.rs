@@ -1,5 +1,5 @@
 use {
-    super::{Array, ArrayMut, GenArray, StaticLenArray},
+    super::{Array, ArrayMut, GenArray, GenerateError},
     num_traits::ToPrimitive,
     smallvec::SmallVec,
 };
@@ -19,8 +19,9 @@ impl<T, const LEN: usize> Array for SmallVec<[T; LEN]> {
 }
 
 impl<T, const LEN: usize> GenArray for SmallVec<[T; LEN]> {
-    fn generate(gen: impl Iterator<Item = Self::Element>) -> Self {
-        gen.collect()
+    #[inline(always)]
+    fn try_generate(gen: impl Iterator<Item = Self::Element>) -> Result<Self, GenerateError> {
+        Ok(gen.collect())
     }
 }
 
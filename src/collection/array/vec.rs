@@ -1,7 +1,11 @@
+#[cfg(feature = "std")]
+use std::collections::VecDeque;
+
 use {
-    super::{Array, ArrayMut, GenArray, StaticLenArray},
+    super::{Array, ArrayMut, GenArray, GenerateError},
     num_traits::ToPrimitive,
 };
+
 impl<T> Array for Vec<T> {
     type Element = T;
 
@@ -27,8 +31,9 @@ impl<T> Array for Vec<T> {
 }
 
 impl<T> GenArray for Vec<T> {
-    fn generate(gen: impl Iterator<Item = Self::Element>) -> Self {
-        gen.collect()
+    #[inline(always)]
+    fn try_generate(gen: impl Iterator<Item = Self::Element>) -> Result<Self, GenerateError> {
+        Ok(gen.collect())
     }
 }
 
@@ -38,3 +43,54 @@ impl<T> ArrayMut for Vec<T> {
         self.get_mut(n)
     }
 }
+
+#[cfg(feature = "std")]
+impl<T> Array for VecDeque<T> {
+    type Element = T;
+
+    #[inline(always)]
+    fn nth(&self, n: usize) -> Option<&T> {
+        self.get(n)
+    }
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.len()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> GenArray for VecDeque<T> {
+    #[inline(always)]
+    fn try_generate(gen: impl Iterator<Item = Self::Element>) -> Result<Self, GenerateError> {
+        Ok(gen.collect())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> ArrayMut for VecDeque<T> {
+    #[inline(always)]
+    fn nth_mut(&mut self, n: usize) -> Option<&mut T> {
+        self.get_mut(n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {crate::collection::*, std::collections::VecDeque};
+
+    #[test]
+    fn deque() {
+        let mut deque: VecDeque<i32> = (1..=4).collect();
+        deque.rotate_left(1);
+        assert_eq!(deque.nth(0), Some(&2));
+        assert_eq!(deque.len(), 4);
+
+        deque.for_each(|x| *x *= 2);
+        assert_eq!(deque, VecDeque::from(vec![4, 6, 8, 2]));
+        assert_eq!(
+            VecDeque::generate((1..=4).map(|x| x * x)),
+            VecDeque::from(vec![1, 4, 9, 16])
+        );
+    }
+}
@@ -0,0 +1,327 @@
+//!
+//!Zero-copy views over other arrays
+//!
+//!Each view wraps the array it looks into, which is usually a reference like `&A` or `&mut A`, and
+//!maps its indices onto that array's. Views are arrays themselves, so they can be nested and passed
+//!to anything generic over [Array], and they are [ArrayMut] whenever the array underneath is.
+//!
+//!```
+//!# use traitor::collection::*;
+//!let v = vec![1, 2, 3, 4, 5, 6, 7];
+//!let window = v.slice(2..6);
+//!assert_eq!(window.iter().sum::<i32>(), 18);
+//!assert_eq!(window.reversed().strided(2).iter().copied().collect::<Vec<_>>(), vec![6, 4]);
+//!```
+//!
+
+use {
+    super::{Array, ArrayMut},
+    core::ops::{Bound, RangeBounds},
+};
+
+///
+///A contiguous range of another array
+///
+///Created by [Array::slice], [ArrayMut::slice_mut] or [ArraySlice::new]
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ArraySlice<A: Array> {
+    array: A,
+    start: usize,
+    len: usize,
+}
+
+impl<A: Array> ArraySlice<A> {
+    ///A view of the elements of `array` in `range`
+    ///
+    ///Panics if the range is out of bounds
+    pub fn new(array: A, range: impl RangeBounds<usize>) -> Self {
+        let start = match range.start_bound() {
+            Bound::Included(&s) => Some(s),
+            Bound::Excluded(&s) => s.checked_add(1),
+            Bound::Unbounded => Some(0),
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&e) => e.checked_add(1),
+            Bound::Excluded(&e) => Some(e),
+            Bound::Unbounded => Some(array.len()),
+        };
+        let (start, end) = match (start, end) {
+            (Some(s), Some(e)) if s <= e && e <= array.len() => (s, e),
+            _ => panic!("Attempted to slice an array out of bounds"),
+        };
+
+        ArraySlice {
+            array,
+            start,
+            len: end - start,
+        }
+    }
+
+    ///The array this is a view into
+    #[inline(always)]
+    pub fn into_inner(self) -> A {
+        self.array
+    }
+}
+
+impl<A: Array> Array for ArraySlice<A> {
+    type Element = A::Element;
+
+    #[inline(always)]
+    fn nth(&self, n: usize) -> Option<&A::Element> {
+        if n < self.len {
+            self.array.nth(self.start + n)
+        } else {
+            None
+        }
+    }
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+impl<A: ArrayMut> ArrayMut for ArraySlice<A> {
+    #[inline(always)]
+    fn nth_mut(&mut self, n: usize) -> Option<&mut A::Element> {
+        if n < self.len {
+            self.array.nth_mut(self.start + n)
+        } else {
+            None
+        }
+    }
+}
+
+///
+///Every `step`th element of another array, starting from the first
+///
+///Created by [Array::strided], [ArrayMut::strided_mut] or [Strided::new]
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Strided<A: Array> {
+    array: A,
+    step: usize,
+}
+
+impl<A: Array> Strided<A> {
+    ///A view of every `step`th element of `array`
+    ///
+    ///Panics if `step` is zero
+    pub fn new(array: A, step: usize) -> Self {
+        if step == 0 {
+            panic!("Attempted to stride an array with a step of zero")
+        }
+        Strided { array, step }
+    }
+
+    ///The array this is a view into
+    #[inline(always)]
+    pub fn into_inner(self) -> A {
+        self.array
+    }
+}
+
+impl<A: Array> Array for Strided<A> {
+    type Element = A::Element;
+
+    #[inline(always)]
+    fn nth(&self, n: usize) -> Option<&A::Element> {
+        self.array.nth(n.checked_mul(self.step)?)
+    }
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.array.len().div_ceil(self.step)
+    }
+}
+
+impl<A: ArrayMut> ArrayMut for Strided<A> {
+    #[inline(always)]
+    fn nth_mut(&mut self, n: usize) -> Option<&mut A::Element> {
+        self.array.nth_mut(n.checked_mul(self.step)?)
+    }
+}
+
+///
+///Another array in reverse order
+///
+///Created by [Array::reversed], [ArrayMut::reversed_mut] or [Reversed::new]
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Reversed<A: Array> {
+    array: A,
+}
+
+impl<A: Array> Reversed<A> {
+    ///A view of `array` from back to front
+    #[inline(always)]
+    pub fn new(array: A) -> Self {
+        Reversed { array }
+    }
+
+    ///The array this is a view into
+    #[inline(always)]
+    pub fn into_inner(self) -> A {
+        self.array
+    }
+}
+
+impl<A: Array> Array for Reversed<A> {
+    type Element = A::Element;
+
+    #[inline(always)]
+    fn nth(&self, n: usize) -> Option<&A::Element> {
+        self.array
+            .nth(self.array.len().checked_sub(n)?.checked_sub(1)?)
+    }
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.array.len()
+    }
+}
+
+impl<A: ArrayMut> ArrayMut for Reversed<A> {
+    #[inline(always)]
+    fn nth_mut(&mut self, n: usize) -> Option<&mut A::Element> {
+        let i = self.array.len().checked_sub(n)?.checked_sub(1)?;
+        self.array.nth_mut(i)
+    }
+}
+
+///
+///Two arrays with the same element type, one after the other
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Chain<A: Array, B: Array<Element = A::Element>> {
+    first: A,
+    second: B,
+}
+
+impl<A: Array, B: Array<Element = A::Element>> Chain<A, B> {
+    ///A view of the elements of `first` followed by those of `second`
+    #[inline(always)]
+    pub fn new(first: A, second: B) -> Self {
+        Chain { first, second }
+    }
+
+    ///The arrays this is a view into
+    #[inline(always)]
+    pub fn into_inner(self) -> (A, B) {
+        (self.first, self.second)
+    }
+}
+
+impl<A: Array, B: Array<Element = A::Element>> Array for Chain<A, B> {
+    type Element = A::Element;
+
+    #[inline(always)]
+    fn nth(&self, n: usize) -> Option<&A::Element> {
+        match n.checked_sub(self.first.len()) {
+            None => self.first.nth(n),
+            Some(m) => self.second.nth(m),
+        }
+    }
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.first.len() + self.second.len()
+    }
+}
+
+impl<A: ArrayMut, B: ArrayMut<Element = A::Element>> ArrayMut for Chain<A, B> {
+    #[inline(always)]
+    fn nth_mut(&mut self, n: usize) -> Option<&mut A::Element> {
+        match n.checked_sub(self.first.len()) {
+            None => self.first.nth_mut(n),
+            Some(m) => self.second.nth_mut(m),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{analysis::Metric, collection::*};
+
+    #[test]
+    fn views() {
+        let v: Vec<i32> = (0..10).collect();
+
+        //sliding window sums without copying
+        let sums: Vec<i32> = (0..=7).map(|i| v.slice(i..i + 3).iter().sum()).collect();
+        assert_eq!(sums, vec![3, 6, 9, 12, 15, 18, 21, 24]);
+
+        let s = v.slice(2..=5);
+        assert_eq!(s.len(), 4);
+        assert_eq!(s.nth(4), None);
+        assert_eq!(
+            s.slice(1..).iter().copied().collect::<Vec<_>>(),
+            vec![3, 4, 5]
+        );
+        assert_eq!(v.slice(..0).len(), 0);
+
+        let t = v.strided(3);
+        assert_eq!(t.len(), 4);
+        assert_eq!(t.iter().copied().collect::<Vec<_>>(), vec![0, 3, 6, 9]);
+        assert_eq!(v.strided(5).len(), 2);
+        assert_eq!(v.strided(20).len(), 1);
+
+        let r = Reversed::new(v.slice(..4));
+        assert_eq!(r.iter().copied().collect::<Vec<_>>(), vec![3, 2, 1, 0]);
+
+        let w = [10, 11];
+        let c = Chain::new(&w, v.slice(..3));
+        assert_eq!(c.iter().copied().collect::<Vec<_>>(), vec![10, 11, 0, 1, 2]);
+        assert_eq!(c.nth(5), None);
+
+        //array-generic code like equality and metrics works on views
+        assert!(v.slice(1..4).array_eq(&v.slice(1..4)));
+        assert!(!v.slice(1..4).array_eq(&v.slice(2..5)));
+        let x = [0.0, 3.0, 1.0, 4.0];
+        let y = [0.0, 5.0, 4.0, 8.0];
+        assert_eq!(EuclideanMetric.distance(&x.slice(1..), &y.slice(..3)), 5.0);
+    }
+
+    #[test]
+    fn mutable_views() {
+        let mut v: Vec<i32> = (0..8).collect();
+
+        v.slice_mut(2..5).for_each(|x| *x = -*x);
+        assert_eq!(v, vec![0, 1, -2, -3, -4, 5, 6, 7]);
+
+        v.strided_mut(2).map_inplace(|x| x * 10);
+        assert_eq!(v, vec![0, 1, -20, -3, -40, 5, 60, 7]);
+
+        *v.reversed_mut().nth_mut(0).unwrap() = 100;
+        assert_eq!(v[7], 100);
+
+        let mut w = [0; 2];
+        let mut c = Chain::new(&mut w, v.slice_mut(..2));
+        c.for_each(|x| *x += 1);
+        assert_eq!(w, [1, 1]);
+        assert_eq!(v[..2], [1, 2]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn out_of_bounds() {
+        [1, 2, 3].slice(1..4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn inclusive_end_overflow() {
+        [1, 2, 3].slice(..=usize::MAX);
+    }
+
+    #[test]
+    fn largest_index() {
+        let mut v = vec![1, 2, 3];
+        assert_eq!(v.reversed().nth(usize::MAX), None);
+        assert_eq!(v.reversed_mut().nth_mut(usize::MAX), None);
+        assert_eq!(v.slice(1..).nth(usize::MAX), None);
+        assert_eq!(v.strided(2).nth(usize::MAX), None);
+    }
+}
@@ -0,0 +1,451 @@
+//!
+//!Checkers for the laws promised by the algebraic marker traits
+//!
+//!Traits like [AddAssociative] and [Distributive] have no methods, so nothing stops them from being
+//!implemented on a type that doesn't actually satisfy them. The functions in this module test those
+//!promises against a set of sample elements, checking every law for every pair (or triple) of samples
+//!and returning the first [violation](LawViolation) found, if any.
+//!
+//!This way, any implementor can get a one-line test like
+//!```
+//!# use traitor::{algebra::Rational, laws::*};
+//!let samples = [Rational::new(1, 2), Rational::new(-3, 4), Rational::from(0), Rational::from(5)];
+//!check_ordered_field_laws(&samples).unwrap();
+//!```
+//!
+//!Since floating point arithmetic is only approximately associative or distributive, every comparison
+//!is done with [ApproxEq], which uses a relative tolerance for floats and exact equality otherwise.
+//!For the same reason, float samples should generally be kept to within a few orders of magnitude
+//!of each other, as otherwise, the rounding error can dwarf the smaller values.
+//!
+//!This module is only available with the `laws` feature.
+//!
+
+use {
+    crate::{
+        algebra::*,
+        analysis::{complex::Complex, ComplexRing, InnerProductSpace, Metric, OrdField, Real},
+        linear::{Matrix, VecN},
+    },
+    std::fmt::{self, Debug, Display, Formatter},
+};
+
+///
+///Equality up to the rounding error of the underlying representation
+///
+///This defaults to `==` for every [PartialEq] type, but is specialized for floats to allow for a small
+///relative error, along with any [complex numbers](Complex), [vectors](VecN), and [matrices](Matrix)
+///built from them.
+///
+pub trait ApproxEq {
+    fn approx_eq(&self, rhs: &Self) -> bool;
+}
+
+impl<T: PartialEq> ApproxEq for T {
+    #[inline]
+    default fn approx_eq(&self, rhs: &Self) -> bool {
+        self == rhs
+    }
+}
+
+macro_rules! impl_approx_eq {
+    ($($f:ident:$tol:expr)*) => {
+        $(
+            impl ApproxEq for $f {
+                #[inline]
+                fn approx_eq(&self, rhs: &Self) -> bool {
+                    self == rhs || (self - rhs).abs() <= $tol * self.abs().max(rhs.abs()).max(1.0)
+                }
+            }
+        )*
+    };
+}
+impl_approx_eq!(f32:1e-4 f64:1e-9);
+
+impl<R: Real> ApproxEq for Complex<R> {
+    #[inline]
+    fn approx_eq(&self, rhs: &Self) -> bool {
+        self.re.approx_eq(&rhs.re) && self.im.approx_eq(&rhs.im)
+    }
+}
+
+impl<T: PartialEq, const N: usize> ApproxEq for VecN<T, N> {
+    #[inline]
+    fn approx_eq(&self, rhs: &Self) -> bool {
+        self.0.iter().zip(rhs.0.iter()).all(|(x, y)| x.approx_eq(y))
+    }
+}
+
+impl<T: PartialEq, const M: usize, const N: usize> ApproxEq for Matrix<T, M, N> {
+    #[inline]
+    fn approx_eq(&self, rhs: &Self) -> bool {
+        self.0
+            .iter()
+            .flatten()
+            .zip(rhs.0.iter().flatten())
+            .all(|(x, y)| x.approx_eq(y))
+    }
+}
+
+///A law that failed to hold along with the samples that broke it
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct LawViolation {
+    ///A description of the law
+    pub law: &'static str,
+    ///The samples that the law failed for
+    pub inputs: String,
+}
+
+impl Display for LawViolation {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{} does not hold for {}", self.law, self.inputs)
+    }
+}
+
+impl std::error::Error for LawViolation {}
+
+///The result of checking a set of laws
+pub type LawResult = Result<(), LawViolation>;
+
+#[inline]
+fn require(holds: bool, law: &'static str, inputs: impl FnOnce() -> String) -> LawResult {
+    if holds {
+        Ok(())
+    } else {
+        Err(LawViolation {
+            law,
+            inputs: inputs(),
+        })
+    }
+}
+
+///Checks that addition forms an [abelian group](AddAbelianGroup)
+pub fn check_add_group_laws<G: AddAbelianGroup + ApproxEq + Debug>(samples: &[G]) -> LawResult {
+    for a in samples {
+        require(
+            (a.clone() + G::zero()).approx_eq(a),
+            "additive identity (a+0 = a)",
+            || format!("a = {:?}", a),
+        )?;
+        require(
+            (a.clone() + -a.clone()).approx_eq(&G::zero()),
+            "additive inverse (a + -a = 0)",
+            || format!("a = {:?}", a),
+        )?;
+
+        for b in samples {
+            require(
+                (a.clone() + b.clone()).approx_eq(&(b.clone() + a.clone())),
+                "additive commutativity (a+b = b+a)",
+                || format!("a = {:?}, b = {:?}", a, b),
+            )?;
+            require(
+                (a.clone() - b.clone()).approx_eq(&(a.clone() + -b.clone())),
+                "subtraction (a-b = a + -b)",
+                || format!("a = {:?}, b = {:?}", a, b),
+            )?;
+
+            for c in samples {
+                require(
+                    ((a.clone() + b.clone()) + c.clone())
+                        .approx_eq(&(a.clone() + (b.clone() + c.clone()))),
+                    "additive associativity ((a+b)+c = a+(b+c))",
+                    || format!("a = {:?}, b = {:?}, c = {:?}", a, b, c),
+                )?;
+            }
+        }
+    }
+    Ok(())
+}
+
+///Checks the laws of a (not necessarily unital or commutative) [Ring]
+pub fn check_ring_laws<R: Ring + ApproxEq + Debug>(samples: &[R]) -> LawResult {
+    check_add_group_laws(samples)?;
+
+    for a in samples {
+        for b in samples {
+            for c in samples {
+                let inputs = || format!("a = {:?}, b = {:?}, c = {:?}", a, b, c);
+                require(
+                    ((a.clone() * b.clone()) * c.clone())
+                        .approx_eq(&(a.clone() * (b.clone() * c.clone()))),
+                    "multiplicative associativity ((a*b)*c = a*(b*c))",
+                    inputs,
+                )?;
+                require(
+                    (a.clone() * (b.clone() + c.clone()))
+                        .approx_eq(&(a.clone() * b.clone() + a.clone() * c.clone())),
+                    "left distributivity (a*(b+c) = a*b + a*c)",
+                    inputs,
+                )?;
+                require(
+                    ((a.clone() + b.clone()) * c.clone())
+                        .approx_eq(&(a.clone() * c.clone() + b.clone() * c.clone())),
+                    "right distributivity ((a+b)*c = a*c + b*c)",
+                    inputs,
+                )?;
+            }
+        }
+    }
+    Ok(())
+}
+
+///Checks the laws of a [ring](Ring) with a multiplicative identity
+pub fn check_unital_ring_laws<R: UnitalRing + ApproxEq + Debug>(samples: &[R]) -> LawResult {
+    check_ring_laws(samples)?;
+
+    for a in samples {
+        require(
+            (R::one() * a.clone()).approx_eq(a) && (a.clone() * R::one()).approx_eq(a),
+            "multiplicative identity (1*a = a*1 = a)",
+            || format!("a = {:?}", a),
+        )?;
+    }
+    Ok(())
+}
+
+///Checks the laws of a [commutative ring](CommutativeRing)
+pub fn check_commutative_ring_laws<R: CommutativeRing + ApproxEq + Debug>(
+    samples: &[R],
+) -> LawResult {
+    check_unital_ring_laws(samples)?;
+
+    for a in samples {
+        for b in samples {
+            require(
+                (a.clone() * b.clone()).approx_eq(&(b.clone() * a.clone())),
+                "multiplicative commutativity (a*b = b*a)",
+                || format!("a = {:?}, b = {:?}", a, b),
+            )?;
+        }
+    }
+    Ok(())
+}
+
+///Checks the laws of a [Field], skipping any zero samples when dividing
+pub fn check_field_laws<K: Field + ApproxEq + Debug>(samples: &[K]) -> LawResult {
+    check_commutative_ring_laws(samples)?;
+
+    for b in samples.iter().filter(|b| !b.is_zero()) {
+        require(
+            (b.clone() * b.clone().inv()).approx_eq(&K::one()),
+            "multiplicative inverse (b * b⁻¹ = 1)",
+            || format!("b = {:?}", b),
+        )?;
+
+        for a in samples {
+            require(
+                (a.clone() / b.clone()).approx_eq(&(a.clone() * b.clone().inv())),
+                "division (a/b = a * b⁻¹)",
+                || format!("a = {:?}, b = {:?}", a, b),
+            )?;
+        }
+    }
+    Ok(())
+}
+
+///Checks the laws of an [ordered field](OrdField)
+pub fn check_ordered_field_laws<K: OrdField + ApproxEq + Debug>(samples: &[K]) -> LawResult {
+    check_field_laws(samples)?;
+
+    for a in samples {
+        for b in samples {
+            require(
+                (a < b) as u8 + (a == b) as u8 + (a > b) as u8 == 1,
+                "trichotomy (exactly one of a<b, a=b, or a>b)",
+                || format!("a = {:?}, b = {:?}", a, b),
+            )?;
+            require(
+                !(a.non_negative() && b.non_negative()) || (a.clone() * b.clone()).non_negative(),
+                "multiplicative order (0<=a and 0<=b implies 0<=a*b)",
+                || format!("a = {:?}, b = {:?}", a, b),
+            )?;
+
+            if a <= b {
+                for c in samples {
+                    require(
+                        a.clone() + c.clone() <= b.clone() + c.clone(),
+                        "additive order (a<=b implies a+c <= b+c)",
+                        || format!("a = {:?}, b = {:?}, c = {:?}", a, b, c),
+                    )?;
+                    //since we already have trichotomy, b > c is equivalent to b <= c failing
+                    require(
+                        b > c || a <= c,
+                        "transitivity (a<=b and b<=c implies a<=c)",
+                        || format!("a = {:?}, b = {:?}, c = {:?}", a, b, c),
+                    )?;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+///Checks that `metric` satisfies the axioms of a [Metric] on the given points
+pub fn check_metric_axioms<X, R, M>(metric: &M, samples: &[X]) -> LawResult
+where
+    X: Clone + PartialEq + Debug,
+    R: Real + Debug,
+    M: Metric<X, R>,
+{
+    for x in samples {
+        require(
+            metric.distance(x.clone(), x.clone()).approx_eq(&R::zero()),
+            "identity (d(x,x) = 0)",
+            || format!("x = {:?}", x),
+        )?;
+
+        for y in samples {
+            let d = metric.distance(x.clone(), y.clone());
+            require(
+                d.non_negative() && (x == y || d.positive()),
+                "positivity (d(x,y) > 0 for x != y)",
+                || format!("x = {:?}, y = {:?}", x, y),
+            )?;
+            require(
+                d.approx_eq(&metric.distance(y.clone(), x.clone())),
+                "symmetry (d(x,y) = d(y,x))",
+                || format!("x = {:?}, y = {:?}", x, y),
+            )?;
+
+            for z in samples {
+                let lhs = metric.distance(x.clone(), z.clone());
+                let rhs = d.clone() + metric.distance(y.clone(), z.clone());
+                require(
+                    lhs <= rhs || lhs.approx_eq(&rhs),
+                    "triangle inequality (d(x,z) <= d(x,y) + d(y,z))",
+                    || format!("x = {:?}, y = {:?}, z = {:?}", x, y, z),
+                )?;
+            }
+        }
+    }
+    Ok(())
+}
+
+///
+///Checks that `V` satisfies the axioms of an [inner product space](InnerProductSpace) using the given
+///vectors and scalars
+///
+pub fn check_inner_product_axioms<K, V>(vectors: &[V], scalars: &[K]) -> LawResult
+where
+    K: ComplexRing + Debug,
+    V: InnerProductSpace<K> + Debug,
+{
+    for x in vectors {
+        let norm = x.clone().inner_product(x.clone());
+        require(
+            norm.approx_eq(&norm.clone().conj()) && norm.clone().as_real().non_negative(),
+            "non-negativity (⟨x,x⟩ is real and ⟨x,x⟩ >= 0)",
+            || format!("x = {:?}", x),
+        )?;
+        require(
+            x.is_zero() || !norm.is_zero(),
+            "definiteness (⟨x,x⟩ = 0 only when x = 0)",
+            || format!("x = {:?}", x),
+        )?;
+
+        for y in vectors {
+            let xy = x.clone().inner_product(y.clone());
+            require(
+                xy.approx_eq(&y.clone().inner_product(x.clone()).conj()),
+                "conjugate symmetry (⟨x,y⟩ = conj(⟨y,x⟩))",
+                || format!("x = {:?}, y = {:?}", x, y),
+            )?;
+
+            for c in scalars {
+                require(
+                    (x.clone() * c.clone())
+                        .inner_product(y.clone())
+                        .approx_eq(&(c.clone() * xy.clone())),
+                    "homogeneity (⟨c*x,y⟩ = c*⟨x,y⟩)",
+                    || format!("x = {:?}, y = {:?}, c = {:?}", x, y, c),
+                )?;
+            }
+
+            for z in vectors {
+                require(
+                    (x.clone() + y.clone()).inner_product(z.clone()).approx_eq(
+                        &(x.clone().inner_product(z.clone()) + y.clone().inner_product(z.clone())),
+                    ),
+                    "additivity (⟨x+y,z⟩ = ⟨x,z⟩ + ⟨y,z⟩)",
+                    || format!("x = {:?}, y = {:?}, z = {:?}", x, y, z),
+                )?;
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        algebra::*,
+        analysis::{complex::Complex, InnerProductMetric},
+        collection::EuclideanMetric,
+        laws::*,
+        linear::{Matrix, VecN},
+    };
+
+    #[test]
+    fn primitives() {
+        check_commutative_ring_laws(&[-7i64, -1, 0, 1, 2, 13]).unwrap();
+        check_commutative_ring_laws(&[0u32, 1, 5, 9].map(core::num::Wrapping)).unwrap();
+        check_ordered_field_laws(&[-2.5f64, -1.0, 0.0, 0.1, 1.0, 3.75]).unwrap();
+        check_ordered_field_laws(&[-2.5f32, 0.0, 0.3, 7.0]).unwrap();
+
+        let err = check_field_laws(&[f64::NAN]).unwrap_err();
+        assert_eq!(err.law, "additive identity (a+0 = a)");
+        assert_eq!(err.inputs, "a = NaN");
+    }
+
+    #[test]
+    fn structures() {
+        let q = |n, d| Rational::new(n, d);
+        check_ordered_field_laws(&[q(1, 2), q(-3, 4), q(0, 1), q(5, 1), q(7, 3)]).unwrap();
+        check_field_laws(&[0, 1, 3, 5, 6].map(Zn::<7>::new)).unwrap();
+        check_commutative_ring_laws(&[0, 2, 3, 4, 5].map(Zn::<6>::new)).unwrap();
+        check_commutative_ring_laws(&[
+            BigInt::from(-12345678901234567i64).pow_n(3u32),
+            BigInt::from(0),
+            BigInt::from(u64::MAX),
+        ])
+        .unwrap();
+        check_commutative_ring_laws(&[
+            Polynomial::new(vec![1, 2]),
+            Polynomial::zero(),
+            Polynomial::new(vec![-3, 0, 1]),
+        ])
+        .unwrap();
+        check_field_laws(&[
+            Complex::new(1.0, 2.0),
+            Complex::new(-0.5, 0.0),
+            Complex::new(0.0, 0.0),
+        ])
+        .unwrap();
+        check_unital_ring_laws(&[
+            Matrix([[1, 2], [3, 4]]),
+            Matrix([[0, -1], [1, 0]]),
+            Matrix([[2, 0], [5, 1]]),
+        ])
+        .unwrap();
+        check_unital_ring_laws(&[
+            Matrix([[0.5, 2.0], [3.0, -4.0]]),
+            Matrix([[0.0, -1.0], [1.0, 0.0]]),
+        ])
+        .unwrap();
+    }
+
+    #[test]
+    fn geometry() {
+        let v = [VecN([1.0, 2.0]), VecN([-3.0, 0.5]), VecN([0.0, 0.0])];
+        check_metric_axioms(&EuclideanMetric, &[&v[0], &v[1], &v[2]]).unwrap();
+        check_metric_axioms(&InnerProductMetric, &[1.0, -2.0, 3.5]).unwrap();
+        check_inner_product_axioms(&v, &[2.0, -0.5]).unwrap();
+
+        let z = [
+            VecN([Complex::new(1.0, 1.0), Complex::new(0.0, 2.0)]),
+            VecN([Complex::new(-1.0, 0.5), Complex::new(3.0, 0.0)]),
+        ];
+        check_inner_product_axioms(&z, &[Complex::new(0.0, 1.0), Complex::new(2.0, -1.0)]).unwrap();
+    }
+}
@@ -11,8 +11,11 @@ pub mod algebra;
 pub mod analysis;
 pub mod bound;
 pub mod collection;
+#[cfg(all(feature = "std", any(test, feature = "laws")))]
+pub mod laws;
 pub mod linear;
 pub mod ops;
+pub mod sample;
 
 pub trait IntoClass<Destination> {
     fn into(self) -> Destination;
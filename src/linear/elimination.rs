@@ -0,0 +1,432 @@
+//!
+//!Gaussian elimination and its applications for any [matrix](MatrixLike) with entries in a [Field]
+//!
+//!Every algorithm here only uses the [field](Field) operations, so they work equally well for
+//!floats, [rationals](crate::algebra::Rational) and [finite fields](crate::algebra::Zn). Note however
+//!that the pivots are chosen as the first non-zero entry in each column rather than the largest, so
+//!for floats, the usual caveats about numerical stability apply.
+//!
+//!For [Euclidean domains](EuclideanDomain) like the [integers](Integer), [bareiss_determinant] computes
+//!determinants without ever leaving the ring.
+//!
+
+use {
+    crate::{
+        algebra::*,
+        linear::{Matrix, MatrixLike},
+    },
+    core::marker::PhantomData,
+};
+
+#[cfg(feature = "std")]
+use crate::linear::DynMatrix;
+
+//an empty matrix to use when there is nothing to augment
+pub(super) struct NoColumns<K>(pub(super) PhantomData<K>);
+
+impl<K> MatrixLike for NoColumns<K> {
+    type Scalar = K;
+
+    #[inline]
+    fn rows(&self) -> usize {
+        0
+    }
+    #[inline]
+    fn cols(&self) -> usize {
+        0
+    }
+    fn entry(&self, _: usize, _: usize) -> &K {
+        unreachable!()
+    }
+    fn entry_mut(&mut self, _: usize, _: usize) -> &mut K {
+        unreachable!()
+    }
+    #[inline]
+    fn swap_rows(&mut self, _: usize, _: usize) {}
+}
+
+#[inline]
+fn scale_row<K: Field, A: MatrixLike<Scalar = K>>(m: &mut A, row: usize, from: usize, c: &K) {
+    for j in from..m.cols() {
+        let x = m.entry(row, j).clone() * c.clone();
+        *m.entry_mut(row, j) = x;
+    }
+}
+
+//subtracts `c` times row `src` from row `dest`
+#[inline]
+fn sub_row<K: Field, A: MatrixLike<Scalar = K>>(
+    m: &mut A,
+    dest: usize,
+    src: usize,
+    from: usize,
+    c: &K,
+) {
+    for j in from..m.cols() {
+        let x = m.entry(dest, j).clone() - c.clone() * m.entry(src, j).clone();
+        *m.entry_mut(dest, j) = x;
+    }
+}
+
+///
+///Gauss-Jordan elimination on `m`, applying every row operation to `aug` as well
+///
+///Returns the rank of `m` and the product of the pivots, negated once for every row swap,
+///which is the determinant of `m` whenever it is square and of full rank.
+///
+fn gauss_jordan<K, A, B>(m: &mut A, aug: &mut B) -> (usize, K)
+where
+    K: Field,
+    A: MatrixLike<Scalar = K>,
+    B: MatrixLike<Scalar = K>,
+{
+    let (rows, cols) = (m.rows(), m.cols());
+    let mut rank = 0;
+    let mut det = K::one();
+
+    for c in 0..cols {
+        if rank == rows {
+            break;
+        }
+
+        //find a row with a non-zero entry in this column to pivot on
+        let pivot_row = match (rank..rows).find(|&r| !m.entry(r, c).is_zero()) {
+            Some(r) => r,
+            None => continue,
+        };
+        if pivot_row != rank {
+            m.swap_rows(pivot_row, rank);
+            aug.swap_rows(pivot_row, rank);
+            det = -det;
+        }
+
+        //scale the pivot to one
+        let pivot = m.entry(rank, c).clone();
+        det *= pivot.clone();
+        let pivot_inv = pivot.inv();
+        scale_row(m, rank, c, &pivot_inv);
+        scale_row(aug, rank, 0, &pivot_inv);
+
+        //and clear the rest of the column
+        for r in 0..rows {
+            if r != rank && !m.entry(r, c).is_zero() {
+                let factor = m.entry(r, c).clone();
+                sub_row(m, r, rank, c, &factor);
+                sub_row(aug, r, rank, 0, &factor);
+            }
+        }
+
+        rank += 1;
+    }
+
+    (rank, det)
+}
+
+///
+///Reduces `m` to [reduced row echelon form](https://en.wikipedia.org/wiki/Row_echelon_form#Reduced_row_echelon_form)
+///in place and returns its rank
+///
+pub fn rref<K: Field, A: MatrixLike<Scalar = K>>(m: &mut A) -> usize {
+    gauss_jordan(m, &mut NoColumns(PhantomData)).0
+}
+
+///The dimension of the space spanned by the rows (or equivalently, columns) of `m`
+pub fn rank<K: Field, A: MatrixLike<Scalar = K> + Clone>(m: &A) -> usize {
+    rref(&mut m.clone())
+}
+
+///
+///The determinant of a square matrix using Gaussian elimination
+///
+///Panics if `m` is not square
+///
+pub fn determinant<K: Field, A: MatrixLike<Scalar = K> + Clone>(m: &A) -> K {
+    if !m.is_square() {
+        panic!(
+            "Attempted to take the determinant of a {}x{} matrix",
+            m.rows(),
+            m.cols()
+        )
+    }
+    let (rank, det) = gauss_jordan(&mut m.clone(), &mut NoColumns(PhantomData));
+    if rank == m.rows() {
+        det
+    } else {
+        K::zero()
+    }
+}
+
+///
+///Finds some `x` such that `a*x = b` for a square matrix `a`, if one exists
+///
+///Here `b` can be any matrix with as many rows as `a` (including a [column vector](crate::linear::VecN)),
+///and the solution has the same shape as `b`. When `a` is singular but the system is still consistent,
+///the solution returned is the one where every free variable is zero.
+///
+///Panics if `a` is not square or if `b` has a different number of rows.
+///
+pub fn solve<K, A, B>(a: &A, b: &B) -> Option<B>
+where
+    K: Field,
+    A: MatrixLike<Scalar = K> + Clone,
+    B: MatrixLike<Scalar = K> + Clone,
+{
+    if !a.is_square() || a.rows() != b.rows() {
+        panic!(
+            "Attempted to solve a system with a {}x{} matrix and a {}x{} right-hand side",
+            a.rows(),
+            a.cols(),
+            b.rows(),
+            b.cols()
+        )
+    }
+
+    let mut a = a.clone();
+    let mut x = b.clone();
+    let (rank, _) = gauss_jordan(&mut a, &mut x);
+
+    //any row of zeros in `a` must have zeros on the right-hand side too
+    let consistent = (rank..x.rows()).all(|r| (0..x.cols()).all(|j| x.entry(r, j).is_zero()));
+    if !consistent {
+        return None;
+    }
+
+    //move each row to the row of its pivot variable, leaving the free variables as zero.
+    //Since the pivot of row r is always in a column c >= r, working from the bottom up never
+    //overwrites a row that has yet to be moved.
+    for r in (0..rank).rev() {
+        let c = (r..a.cols())
+            .find(|&c| !a.entry(r, c).is_zero())
+            .unwrap_or(r);
+        if c != r {
+            x.swap_rows(r, c);
+        }
+    }
+    let mut pivot = 0;
+    for c in 0..a.cols() {
+        if pivot < rank && !a.entry(pivot, c).is_zero() {
+            pivot += 1;
+        } else {
+            for j in 0..x.cols() {
+                *x.entry_mut(c, j) = K::zero();
+            }
+        }
+    }
+
+    Some(x)
+}
+
+///
+///The inverse of a square matrix, if it exists, using Gauss-Jordan elimination
+///
+///Returns `None` if `m` is not square or is singular
+///
+pub fn inverse<K: Field, A: MatrixLike<Scalar = K> + Clone>(m: &A) -> Option<A> {
+    let mut id = m.clone();
+    for i in 0..id.rows() {
+        for j in 0..id.cols() {
+            *id.entry_mut(i, j) = if i == j { K::one() } else { K::zero() };
+        }
+    }
+
+    let mut m = m.clone();
+    let (rank, _) = gauss_jordan(&mut m, &mut id);
+    if rank == m.rows() && m.is_square() {
+        Some(id)
+    } else {
+        None
+    }
+}
+
+///
+///The determinant of a square matrix using the fraction-free
+///[Bareiss algorithm](https://en.wikipedia.org/wiki/Bareiss_algorithm)
+///
+///Every division performed is exact, so this only requires the entries to be in a
+///[Euclidean domain](EuclideanDomain) and the intermediate values stay bounded by the size of the minors.
+///
+///Panics if `m` is not square
+///
+pub fn bareiss_determinant<R: EuclideanDomain, A: MatrixLike<Scalar = R> + Clone>(m: &A) -> R {
+    if !m.is_square() {
+        panic!(
+            "Attempted to take the determinant of a {}x{} matrix",
+            m.rows(),
+            m.cols()
+        )
+    }
+    let n = m.rows();
+    if n == 0 {
+        return R::one();
+    }
+
+    let mut m = m.clone();
+    let mut negate = false;
+    let mut prev = R::one();
+    for k in 0..n - 1 {
+        if m.entry(k, k).is_zero() {
+            match (k + 1..n).find(|&r| !m.entry(r, k).is_zero()) {
+                Some(r) => {
+                    m.swap_rows(k, r);
+                    negate = !negate;
+                }
+                None => return R::zero(),
+            }
+        }
+
+        for i in k + 1..n {
+            for j in k + 1..n {
+                let x = m.entry(i, j).clone() * m.entry(k, k).clone()
+                    - m.entry(i, k).clone() * m.entry(k, j).clone();
+                *m.entry_mut(i, j) = x.div_euc(prev.clone());
+            }
+        }
+        prev = m.entry(k, k).clone();
+    }
+
+    let det = m.entry(n - 1, n - 1).clone();
+    if negate {
+        -det
+    } else {
+        det
+    }
+}
+
+//
+//Divisibility
+//
+//A square matrix divides another exactly when the corresponding system of equations is consistent,
+//and the units are precisely the invertible matrices
+//
+
+impl<K: Field, const N: usize> Divisibility for Matrix<K, N, N> {
+    #[inline]
+    fn divides(self, rhs: Self) -> bool {
+        solve(&self, &rhs).is_some()
+    }
+    #[inline]
+    fn divide(self, rhs: Self) -> Option<Self> {
+        solve(&rhs, &self)
+    }
+    #[inline]
+    fn unit(&self) -> bool {
+        !determinant(self).is_zero()
+    }
+    #[inline]
+    fn inverse(self) -> Option<Self> {
+        inverse(&self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K: Field> Divisibility for DynMatrix<K> {
+    #[inline]
+    fn divides(self, rhs: Self) -> bool {
+        solve(&self, &rhs).is_some()
+    }
+    #[inline]
+    fn divide(self, rhs: Self) -> Option<Self> {
+        solve(&rhs, &self)
+    }
+    #[inline]
+    fn unit(&self) -> bool {
+        self.is_square() && !determinant(self).is_zero()
+    }
+    #[inline]
+    fn inverse(self) -> Option<Self> {
+        if self.is_square() {
+            inverse(&self)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        algebra::*,
+        linear::{elimination::*, Matrix, VecN},
+    };
+
+    fn q(n: i64) -> Rational<i64> {
+        Rational::from(n)
+    }
+
+    #[test]
+    fn row_reduction() {
+        let mut m = Matrix([[q(1), q(2), q(3)], [q(2), q(4), q(6)], [q(1), q(0), q(1)]]);
+        assert_eq!(rank(&m), 2);
+        assert_eq!(rref(&mut m), 2);
+        assert_eq!(
+            m,
+            Matrix([[q(1), q(0), q(1)], [q(0), q(1), q(1)], [q(0), q(0), q(0)]])
+        );
+
+        assert_eq!(rank(&Matrix([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]])), 2);
+        assert_eq!(rank(&Matrix::<f64, 2, 2>::zero()), 0);
+    }
+
+    #[test]
+    fn determinants() {
+        let m = Matrix([[2.0, 1.0], [1.0, 3.0]]);
+        assert_eq!(determinant(&m), 5.0);
+
+        let m = Matrix([[0, 2, 1], [3, -1, 4], [5, 2, -2]]);
+        let det = determinant(&Matrix::<_, 3, 3>::from_fn(|i, j| q(m[(i, j)])));
+        assert_eq!(det, q(63));
+        assert_eq!(bareiss_determinant(&m), 63);
+        assert_eq!(bareiss_determinant(&Matrix([[1, 2], [2, 4]])), 0);
+        assert_eq!(bareiss_determinant(&Matrix::<i32, 0, 0>([])), 1);
+    }
+
+    #[test]
+    fn inverses() {
+        let m = Matrix([[q(2), q(1)], [q(5), q(3)]]);
+        let inv = m.inverse().unwrap();
+        assert_eq!(inv, Matrix([[q(3), q(-1)], [q(-5), q(2)]]));
+        assert_eq!(m * inv, Matrix::identity());
+        assert!(Matrix([[q(1), q(2)], [q(2), q(4)]]).inverse().is_none());
+        assert!(inverse(&Matrix([[q(1), q(2), q(3)], [q(4), q(5), q(6)]])).is_none());
+
+        //over the finite field of 7 elements
+        let m = Matrix([[Zn::<7>::new(3), Zn::new(1)], [Zn::new(4), Zn::new(2)]]);
+        assert!(m.unit());
+        assert!(m * m.inverse().unwrap() == Matrix::identity());
+    }
+
+    #[test]
+    fn solving() {
+        let a = Matrix([[q(1), q(1), q(1)], [q(0), q(2), q(5)], [q(2), q(5), q(-1)]]);
+        let b = VecN([q(6), q(-4), q(27)]);
+        assert_eq!(solve(&a, &b), Some(VecN([q(5), q(3), q(-2)])));
+
+        //consistent but singular, so the free variable is set to zero
+        let a = Matrix([[q(1), q(2)], [q(2), q(4)]]);
+        assert_eq!(solve(&a, &VecN([q(3), q(6)])), Some(VecN([q(3), q(0)])));
+        assert_eq!(solve(&a, &VecN([q(3), q(7)])), None);
+
+        let a = Matrix([[q(0), q(1)], [q(0), q(0)]]);
+        assert_eq!(solve(&a, &VecN([q(5), q(0)])), Some(VecN([q(0), q(5)])));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn dynamic() {
+        use crate::linear::DynMatrix;
+
+        let m = DynMatrix::new(3, 3, vec![0, 2, 1, 3, -1, 4, 5, 2, -2]);
+        assert_eq!(bareiss_determinant(&m), 63);
+
+        let big = DynMatrix::from_fn(3, 3, |i, j| BigInt::from(m[(i, j)]).pow_n(20u32));
+        let exact = DynMatrix::from_fn(3, 3, |i, j| Rational::from(big[(i, j)].clone()));
+        assert_eq!(
+            Rational::from(bareiss_determinant(&big)),
+            determinant(&exact)
+        );
+
+        let r = DynMatrix::from_fn(3, 3, |i, j| q(m[(i, j)]));
+        let inv = r.clone().inverse().unwrap();
+        assert_eq!(r * inv, DynMatrix::identity(3));
+    }
+}
@@ -1,3 +1,5 @@
+pub mod elimination;
+pub mod normal_form;
 pub mod tensor;
 pub mod vector;
 
@@ -0,0 +1,390 @@
+//!
+//!Hermite and Smith normal forms for matrices over a [Euclidean domain](EuclideanDomain)
+//!
+//!Unlike [Gaussian elimination](super::elimination), these only ever use _unimodular_ row and column
+//!operations, ie, those that are invertible over the ring itself. As such, these are the
+//!tools of choice for working with integer lattices and finitely generated modules. For instance,
+//![invariant_factors] gives the decomposition of the abelian group generated by the columns
+//!of an integer matrix modulo its rows.
+//!
+//!Every operation is built out of the [Bezout coefficients](Bezout) of a pair of entries, and
+//!each function comes in a variant that applies the same operations to a second (and third) matrix.
+//!Starting those off as the identity then records the unimodular transforms that were used.
+//!
+//!For ordered rings like the integers, pivots are made positive and the entries above them are
+//!reduced to be non-negative, making the results unique. For other rings, the pivots are only
+//!determined up to multiplication by a unit.
+//!
+
+use {
+    super::elimination::NoColumns,
+    crate::{
+        algebra::*,
+        analysis::ordered::Signed,
+        linear::{Matrix, MatrixLike},
+    },
+    core::marker::PhantomData,
+};
+
+#[cfg(feature = "std")]
+use crate::linear::DynMatrix;
+
+//Canonical representatives for associates and remainders
+trait Canonical: EuclideanDomain {
+    //a unit to multiply by to get the canonical associate of this element, if it isn't already
+    fn normalizer(&self) -> Option<Self>;
+    //the quotient of `self` by `rhs` that leaves the canonical remainder
+    fn canonical_div(self, rhs: Self) -> Self;
+}
+
+impl<R: EuclideanDomain> Canonical for R {
+    #[inline]
+    default fn normalizer(&self) -> Option<Self> {
+        None
+    }
+    #[inline]
+    default fn canonical_div(self, rhs: Self) -> Self {
+        self.div_euc(rhs)
+    }
+}
+
+impl<R: EuclideanDomain + PartialOrd> Canonical for R {
+    #[inline]
+    fn normalizer(&self) -> Option<Self> {
+        if self.negative() {
+            Some(-R::one())
+        } else {
+            None
+        }
+    }
+    #[inline]
+    fn canonical_div(self, rhs: Self) -> Self {
+        let q = self.clone().div_euc(rhs.clone());
+        if (self - q.clone() * rhs.clone()).negative() {
+            if rhs.positive() {
+                q - R::one()
+            } else {
+                q + R::one()
+            }
+        } else {
+            q
+        }
+    }
+}
+
+//replaces rows `i` and `j` with `a*rᵢ + b*rⱼ` and `c*rᵢ + d*rⱼ`
+fn mix_rows<R: Ring, A: MatrixLike<Scalar = R>>(m: &mut A, i: usize, j: usize, e: &[R; 4]) {
+    let [a, b, c, d] = e;
+    for k in 0..m.cols() {
+        let (x, y) = (m.entry(i, k).clone(), m.entry(j, k).clone());
+        *m.entry_mut(i, k) = a.clone() * x.clone() + b.clone() * y.clone();
+        *m.entry_mut(j, k) = c.clone() * x + d.clone() * y;
+    }
+}
+
+//replaces columns `i` and `j` with `a*cᵢ + b*cⱼ` and `c*cᵢ + d*cⱼ`
+fn mix_cols<R: Ring, A: MatrixLike<Scalar = R>>(m: &mut A, i: usize, j: usize, e: &[R; 4]) {
+    let [a, b, c, d] = e;
+    for k in 0..m.rows() {
+        let (x, y) = (m.entry(k, i).clone(), m.entry(k, j).clone());
+        *m.entry_mut(k, i) = a.clone() * x.clone() + b.clone() * y.clone();
+        *m.entry_mut(k, j) = c.clone() * x + d.clone() * y;
+    }
+}
+
+fn scale_row<R: Ring, A: MatrixLike<Scalar = R>>(m: &mut A, i: usize, unit: &R) {
+    for k in 0..m.cols() {
+        let x = unit.clone() * m.entry(i, k).clone();
+        *m.entry_mut(i, k) = x;
+    }
+}
+
+///
+///A unimodular transform taking `(a, b)` to `(gcd(a,b), 0)`
+///
+///The result `[x, y, z, w]` represents the matrix `[[x, y], [z, w]]`, which always has determinant `1`
+///(or `-1` when it just swaps the two).
+///
+fn eliminator<R: EuclideanDomain>(a: R, b: R) -> [R; 4] {
+    if a.is_zero() {
+        [R::zero(), R::one(), R::one(), R::zero()]
+    } else if b.clone().rem_euc(a.clone()).is_zero() {
+        [R::one(), R::zero(), -b.div_euc(a), R::one()]
+    } else {
+        let (x, y, g) = a.clone().bezout_with_gcd(b.clone());
+        [x, y, -b.div_euc(g.clone()), a.div_euc(g)]
+    }
+}
+
+///
+///Reduces `m` to [Hermite normal form](https://en.wikipedia.org/wiki/Hermite_normal_form) in place
+///and returns its rank
+///
+///The result is in row echelon form where, in addition, every entry above a pivot is reduced modulo it.
+///
+pub fn hermite_form<R: EuclideanDomain, A: MatrixLike<Scalar = R>>(m: &mut A) -> usize {
+    hermite_form_with_transform(m, &mut NoColumns(PhantomData))
+}
+
+///
+///Reduces `m` to [Hermite normal form](hermite_form) in place, applying every row operation to `u`,
+///and returns the rank of `m`
+///
+///If `u` starts as the identity, then afterwards it is a unimodular matrix with `u*m₀ = m`.
+///
+pub fn hermite_form_with_transform<R, A, U>(m: &mut A, u: &mut U) -> usize
+where
+    R: EuclideanDomain,
+    A: MatrixLike<Scalar = R>,
+    U: MatrixLike<Scalar = R>,
+{
+    let (rows, cols) = (m.rows(), m.cols());
+    let mut rank = 0;
+
+    for c in 0..cols {
+        if rank == rows {
+            break;
+        }
+
+        //combine everything below into the pivot
+        for i in rank + 1..rows {
+            if !m.entry(i, c).is_zero() {
+                let e = eliminator(m.entry(rank, c).clone(), m.entry(i, c).clone());
+                mix_rows(m, rank, i, &e);
+                mix_rows(u, rank, i, &e);
+            }
+        }
+        if m.entry(rank, c).is_zero() {
+            continue;
+        }
+        if let Some(unit) = m.entry(rank, c).normalizer() {
+            scale_row(m, rank, &unit);
+            scale_row(u, rank, &unit);
+        }
+
+        //and reduce everything above it
+        let pivot = m.entry(rank, c).clone();
+        for i in 0..rank {
+            let q = m.entry(i, c).clone().canonical_div(pivot.clone());
+            if !q.is_zero() {
+                let e = [R::one(), -q, R::zero(), R::one()];
+                mix_rows(m, i, rank, &e);
+                mix_rows(u, i, rank, &e);
+            }
+        }
+
+        rank += 1;
+    }
+
+    rank
+}
+
+///
+///Reduces `m` to [Smith normal form](https://en.wikipedia.org/wiki/Smith_normal_form) in place
+///and returns its rank
+///
+///The result is diagonal, and each entry of the diagonal divides the next.
+///
+pub fn smith_form<R: EuclideanDomain, A: MatrixLike<Scalar = R>>(m: &mut A) -> usize {
+    smith_form_with_transforms(m, &mut NoColumns(PhantomData), &mut NoColumns(PhantomData))
+}
+
+///
+///Reduces `m` to [Smith normal form](smith_form) in place, applying every row operation to `u`
+///and every column operation to `v`, and returns the rank of `m`
+///
+///If `u` and `v` start as identities, then afterwards they are unimodular matrices with `u*m₀*v = m`.
+///
+pub fn smith_form_with_transforms<R, A, U, V>(m: &mut A, u: &mut U, v: &mut V) -> usize
+where
+    R: EuclideanDomain,
+    A: MatrixLike<Scalar = R>,
+    U: MatrixLike<Scalar = R>,
+    V: MatrixLike<Scalar = R>,
+{
+    let (rows, cols) = (m.rows(), m.cols());
+    let swap = [R::zero(), R::one(), R::one(), R::zero()];
+    let mut t = 0;
+
+    while t < rows.min(cols) {
+        //start with the smallest entry left as the pivot
+        let smallest = (t..rows)
+            .flat_map(|i| (t..cols).map(move |j| (i, j)))
+            .filter(|&(i, j)| !m.entry(i, j).is_zero())
+            .min_by_key(|&(i, j)| m.entry(i, j).euclid_norm());
+        let (i, j) = match smallest {
+            Some(p) => p,
+            None => break,
+        };
+        if i != t {
+            mix_rows(m, t, i, &swap);
+            mix_rows(u, t, i, &swap);
+        }
+        if j != t {
+            mix_cols(m, t, j, &swap);
+            mix_cols(v, t, j, &swap);
+        }
+
+        loop {
+            for i in t + 1..rows {
+                if !m.entry(i, t).is_zero() {
+                    let e = eliminator(m.entry(t, t).clone(), m.entry(i, t).clone());
+                    mix_rows(m, t, i, &e);
+                    mix_rows(u, t, i, &e);
+                }
+            }
+            for j in t + 1..cols {
+                if !m.entry(t, j).is_zero() {
+                    let e = eliminator(m.entry(t, t).clone(), m.entry(t, j).clone());
+                    mix_cols(m, t, j, &e);
+                    mix_cols(v, t, j, &e);
+                }
+            }
+
+            //clearing the row can fill the column back in
+            if (t + 1..rows).any(|i| !m.entry(i, t).is_zero()) {
+                continue;
+            }
+
+            //the pivot also has to divide everything that's left, so if it doesn't, we pull
+            //in the offending row and go again with a strictly smaller pivot
+            let pivot = m.entry(t, t).clone();
+            let offender = (t + 1..rows).find(|&i| {
+                (t + 1..cols).any(|j| !m.entry(i, j).clone().rem_euc(pivot.clone()).is_zero())
+            });
+            match offender {
+                Some(i) => {
+                    let e = [R::one(), R::one(), R::zero(), R::one()];
+                    mix_rows(m, t, i, &e);
+                    mix_rows(u, t, i, &e);
+                }
+                None => break,
+            }
+        }
+
+        if let Some(unit) = m.entry(t, t).normalizer() {
+            scale_row(m, t, &unit);
+            scale_row(u, t, &unit);
+        }
+        t += 1;
+    }
+
+    t
+}
+
+///
+///The non-zero diagonal entries of the [Smith normal form](smith_form) of `m`
+///
+///When `m` is an integer matrix, the abelian group `ℤⁿ / mᵀℤᵐ` generated by the columns of `m`
+///modulo its rows is the direct sum of `ℤ/dℤ` for each invariant factor `d` along with a free
+///part of rank `n - rank(m)`.
+///
+#[cfg(feature = "std")]
+pub fn invariant_factors<R: EuclideanDomain, A: MatrixLike<Scalar = R> + Clone>(m: &A) -> Vec<R> {
+    let mut m = m.clone();
+    let rank = smith_form(&mut m);
+    (0..rank).map(|i| m.entry(i, i).clone()).collect()
+}
+
+impl<R: EuclideanDomain, const M: usize, const N: usize> Matrix<R, M, N> {
+    ///
+    ///The [Hermite normal form](hermite_form) `H` of this matrix along with a unimodular
+    ///matrix `U` such that `U*self = H`
+    ///
+    pub fn hermite_normal_form(mut self) -> (Self, Matrix<R, M, M>) {
+        let mut u = Matrix::identity();
+        hermite_form_with_transform(&mut self, &mut u);
+        (self, u)
+    }
+
+    ///
+    ///The [Smith normal form](smith_form) `S` of this matrix along with unimodular
+    ///matrices `U` and `V` such that `U*self*V = S`
+    ///
+    pub fn smith_normal_form(mut self) -> (Self, Matrix<R, M, M>, Matrix<R, N, N>) {
+        let (mut u, mut v) = (Matrix::identity(), Matrix::identity());
+        smith_form_with_transforms(&mut self, &mut u, &mut v);
+        (self, u, v)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R: EuclideanDomain> DynMatrix<R> {
+    ///
+    ///The [Hermite normal form](hermite_form) `H` of this matrix along with a unimodular
+    ///matrix `U` such that `U*self = H`
+    ///
+    pub fn hermite_normal_form(mut self) -> (Self, Self) {
+        let mut u = DynMatrix::identity(self.rows());
+        hermite_form_with_transform(&mut self, &mut u);
+        (self, u)
+    }
+
+    ///
+    ///The [Smith normal form](smith_form) `S` of this matrix along with unimodular
+    ///matrices `U` and `V` such that `U*self*V = S`
+    ///
+    pub fn smith_normal_form(mut self) -> (Self, Self, Self) {
+        let mut u = DynMatrix::identity(self.rows());
+        let mut v = DynMatrix::identity(self.cols());
+        smith_form_with_transforms(&mut self, &mut u, &mut v);
+        (self, u, v)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        algebra::*,
+        linear::{elimination::bareiss_determinant, normal_form::*, Matrix},
+    };
+
+    #[test]
+    fn hermite() {
+        let a = Matrix::<i64, 3, 4>([[2, 3, 6, 2], [5, 6, 1, 6], [8, 3, 1, 1]]);
+        let (h, u) = a.hermite_normal_form();
+        assert_eq!(
+            h,
+            Matrix([[1, 0, 50, -11], [0, 3, 28, -2], [0, 0, 61, -13]])
+        );
+        assert_eq!(u * a, h);
+        assert_eq!(bareiss_determinant(&u).abs(), 1);
+
+        let mut b = Matrix([[0, 4], [0, -6], [0, 0]]);
+        assert_eq!(hermite_form(&mut b), 1);
+        assert_eq!(b, Matrix([[0, 2], [0, 0], [0, 0]]));
+    }
+
+    #[test]
+    fn smith() {
+        let a = Matrix::<i64, 3, 3>([[2, 4, 4], [-6, 6, 12], [10, -4, -16]]);
+        let (s, u, v) = a.smith_normal_form();
+        assert_eq!(s, Matrix([[2, 0, 0], [0, 6, 0], [0, 0, 12]]));
+        assert_eq!(u * a * v, s);
+        assert_eq!(bareiss_determinant(&u).abs(), 1);
+        assert_eq!(bareiss_determinant(&v).abs(), 1);
+
+        let mut b = Matrix([[6, 4], [4, 6], [0, 0]]);
+        assert_eq!(smith_form(&mut b), 2);
+        assert_eq!(b, Matrix([[2, 0], [0, 10], [0, 0]]));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn dynamic() {
+        use crate::linear::DynMatrix;
+
+        //ℤ² / <(2,0), (0,3)> ≅ ℤ/6ℤ
+        let m = DynMatrix::new(2, 2, vec![2, 0, 0, 3]);
+        assert_eq!(invariant_factors(&m), vec![1, 6]);
+
+        let m = DynMatrix::new(2, 3, vec![4, 6, 8, 2, 2, 2]);
+        let (s, u, v) = m.clone().smith_normal_form();
+        assert_eq!(invariant_factors(&m), vec![2, 2]);
+        assert_eq!(u * m.clone() * v, s);
+
+        let (h, u) = m.clone().hermite_normal_form();
+        assert_eq!(h, DynMatrix::new(2, 3, vec![2, 0, -2, 0, 2, 4]));
+        assert_eq!(u * m, h);
+    }
+}
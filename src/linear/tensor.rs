@@ -1 +1,764 @@
+//!
+//!Dense matrices over a ring
+//!
+//!Two representations are provided:
+//!* [Matrix], where the dimensions are compile-time constants
+//!* [DynMatrix], where the dimensions are chosen at runtime and the entries are stored on the heap
+//!
+//!Square [Matrix] types form a [unital ring](UnitalRing) and an [algebra](AssociativeAlgebra) over
+//!their scalars. Since the zero and identity matrices depend on the dimensions, [DynMatrix] instead
+//!only provides the operations themselves, panicking when the dimensions of the operands don't match.
+//!Both implement [MatrixLike] so that algorithms can be written for either.
+//!
 
+use {
+    crate::{algebra::*, linear::VecN},
+    core::{
+        array,
+        ops::{Index, IndexMut},
+    },
+};
+
+#[cfg(feature = "std")]
+pub use self::dynamic::*;
+
+//the sum of `f(k)*g(k)` for each `k` in `0..n`
+#[inline]
+fn sum_of_products<T: Semiring>(n: usize, f: impl Fn(usize) -> T, g: impl Fn(usize) -> T) -> T {
+    (0..n).fold(T::zero(), |acc, k| acc + f(k) * g(k))
+}
+
+///Random access to the entries of a matrix with rows and columns indexed from zero
+pub trait MatrixLike: Sized {
+    type Scalar;
+
+    ///The number of rows
+    fn rows(&self) -> usize;
+
+    ///The number of columns
+    fn cols(&self) -> usize;
+
+    ///The entry in row `i` and column `j`, panicking if either is out of bounds
+    fn entry(&self, i: usize, j: usize) -> &Self::Scalar;
+
+    ///The entry in row `i` and column `j`, panicking if either is out of bounds
+    fn entry_mut(&mut self, i: usize, j: usize) -> &mut Self::Scalar;
+
+    ///Swaps rows `i` and `j`
+    fn swap_rows(&mut self, i: usize, j: usize);
+
+    ///Determines if this matrix has as many rows as columns
+    #[inline]
+    fn is_square(&self) -> bool {
+        self.rows() == self.cols()
+    }
+}
+
+///
+///An `M`x`N` matrix with entries from the ring `T`
+///
+///The entries are stored as an array of rows, so `Matrix([[1, 2], [3, 4]])` has `[1, 2]` as its first row.
+///
+///Matrices of any shape form a `T`-[module](RingModule) using entry-wise addition and scalar
+///multiplication, and matrix multiplication is available between any matrices of compatible shapes
+///as well as with [column vectors](VecN). As such, square matrices form a [ring](UnitalRing), and over
+///a [Field], an [associative algebra](AssociativeAlgebra).
+///
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Matrix<T, const M: usize, const N: usize>(pub [[T; N]; M]);
+
+impl<T, const M: usize, const N: usize> Matrix<T, M, N> {
+    ///Constructs a matrix from its rows
+    #[inline]
+    pub fn new(rows: [[T; N]; M]) -> Self {
+        Matrix(rows)
+    }
+
+    ///Constructs a matrix by computing each entry from its row and column
+    #[inline]
+    pub fn from_fn(mut f: impl FnMut(usize, usize) -> T) -> Self {
+        Matrix(array::from_fn(|i| array::from_fn(|j| f(i, j))))
+    }
+
+    ///Unwraps this matrix into its rows
+    #[inline]
+    pub fn into_rows(self) -> [[T; N]; M] {
+        self.0
+    }
+
+    ///The `i`th row of this matrix
+    #[inline]
+    pub fn row(&self, i: usize) -> &[T; N] {
+        &self.0[i]
+    }
+}
+
+impl<T: Clone, const M: usize, const N: usize> Matrix<T, M, N> {
+    ///The `j`th column of this matrix as a vector
+    #[inline]
+    pub fn col(&self, j: usize) -> VecN<T, M> {
+        VecN(array::from_fn(|i| self.0[i][j].clone()))
+    }
+
+    ///Swaps the rows and columns of this matrix
+    #[inline]
+    pub fn transpose(&self) -> Matrix<T, N, M> {
+        Matrix::from_fn(|i, j| self.0[j][i].clone())
+    }
+}
+
+impl<T: Zero + One, const N: usize> Matrix<T, N, N> {
+    ///The `N`x`N` identity matrix
+    #[inline]
+    pub fn identity() -> Self {
+        Matrix::from_fn(|i, j| if i == j { T::one() } else { T::zero() })
+    }
+}
+
+impl<T: Semiring, const N: usize> Matrix<T, N, N> {
+    ///The sum of the entries along the main diagonal
+    #[inline]
+    pub fn trace(&self) -> T {
+        (0..N).fold(T::zero(), |acc, i| acc + self.0[i][i].clone())
+    }
+}
+
+impl<T, const M: usize, const N: usize> MatrixLike for Matrix<T, M, N> {
+    type Scalar = T;
+
+    #[inline]
+    fn rows(&self) -> usize {
+        M
+    }
+    #[inline]
+    fn cols(&self) -> usize {
+        N
+    }
+    #[inline]
+    fn entry(&self, i: usize, j: usize) -> &T {
+        &self.0[i][j]
+    }
+    #[inline]
+    fn entry_mut(&mut self, i: usize, j: usize) -> &mut T {
+        &mut self.0[i][j]
+    }
+    #[inline]
+    fn swap_rows(&mut self, i: usize, j: usize) {
+        self.0.swap(i, j)
+    }
+}
+
+///A vector acts as a matrix with a single column
+impl<T, const N: usize> MatrixLike for VecN<T, N> {
+    type Scalar = T;
+
+    #[inline]
+    fn rows(&self) -> usize {
+        N
+    }
+    #[inline]
+    fn cols(&self) -> usize {
+        1
+    }
+    #[inline]
+    fn entry(&self, i: usize, j: usize) -> &T {
+        if j != 0 {
+            panic!("Attempted to index column {} of a column vector", j)
+        }
+        &self.0[i]
+    }
+    #[inline]
+    fn entry_mut(&mut self, i: usize, j: usize) -> &mut T {
+        if j != 0 {
+            panic!("Attempted to index column {} of a column vector", j)
+        }
+        &mut self.0[i]
+    }
+    #[inline]
+    fn swap_rows(&mut self, i: usize, j: usize) {
+        self.0.swap(i, j)
+    }
+}
+
+impl<T, const M: usize, const N: usize> From<[[T; N]; M]> for Matrix<T, M, N> {
+    #[inline]
+    fn from(rows: [[T; N]; M]) -> Self {
+        Matrix(rows)
+    }
+}
+
+impl<T, const M: usize, const N: usize> Index<(usize, usize)> for Matrix<T, M, N> {
+    type Output = T;
+    #[inline]
+    fn index(&self, (i, j): (usize, usize)) -> &T {
+        &self.0[i][j]
+    }
+}
+
+impl<T, const M: usize, const N: usize> IndexMut<(usize, usize)> for Matrix<T, M, N> {
+    #[inline]
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut T {
+        &mut self.0[i][j]
+    }
+}
+
+//
+//Unary operations
+//
+
+impl<T: Neg<Output = T>, const M: usize, const N: usize> Neg for Matrix<T, M, N> {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Matrix(self.0.map(|row| row.map(T::neg)))
+    }
+}
+
+//
+//Binary operations
+//
+
+impl<T: AddAssign, const M: usize, const N: usize> AddAssign for Matrix<T, M, N> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        for (r1, r2) in self.0.iter_mut().zip(IntoIterator::into_iter(rhs.0)) {
+            for (x, y) in r1.iter_mut().zip(IntoIterator::into_iter(r2)) {
+                *x += y;
+            }
+        }
+    }
+}
+
+impl<T: SubAssign, const M: usize, const N: usize> SubAssign for Matrix<T, M, N> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        for (r1, r2) in self.0.iter_mut().zip(IntoIterator::into_iter(rhs.0)) {
+            for (x, y) in r1.iter_mut().zip(IntoIterator::into_iter(r2)) {
+                *x -= y;
+            }
+        }
+    }
+}
+
+impl<T: Clone + MulAssign, const M: usize, const N: usize> MulAssign<T> for Matrix<T, M, N> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: T) {
+        for x in self.0.iter_mut().flat_map(|row| row.iter_mut()) {
+            *x *= rhs.clone();
+        }
+    }
+}
+
+impl<T: Clone + DivAssign, const M: usize, const N: usize> DivAssign<T> for Matrix<T, M, N> {
+    #[inline]
+    fn div_assign(&mut self, rhs: T) {
+        for x in self.0.iter_mut().flat_map(|row| row.iter_mut()) {
+            *x /= rhs.clone();
+        }
+    }
+}
+
+impl<T: Semiring, const N: usize> MulAssign for Matrix<T, N, N> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = self.clone() * rhs;
+    }
+}
+
+impl<T: AddAssign, const M: usize, const N: usize> Add for Matrix<T, M, N> {
+    type Output = Self;
+    #[inline]
+    fn add(mut self, rhs: Self) -> Self {
+        self += rhs;
+        self
+    }
+}
+
+impl<T: SubAssign, const M: usize, const N: usize> Sub for Matrix<T, M, N> {
+    type Output = Self;
+    #[inline]
+    fn sub(mut self, rhs: Self) -> Self {
+        self -= rhs;
+        self
+    }
+}
+
+impl<T: Clone + MulAssign, const M: usize, const N: usize> Mul<T> for Matrix<T, M, N> {
+    type Output = Self;
+    #[inline]
+    fn mul(mut self, rhs: T) -> Self {
+        self *= rhs;
+        self
+    }
+}
+
+impl<T: Clone + DivAssign, const M: usize, const N: usize> Div<T> for Matrix<T, M, N> {
+    type Output = Self;
+    #[inline]
+    fn div(mut self, rhs: T) -> Self {
+        self /= rhs;
+        self
+    }
+}
+
+impl<T: Semiring, const M: usize, const N: usize, const P: usize> Mul<Matrix<T, N, P>>
+    for Matrix<T, M, N>
+{
+    type Output = Matrix<T, M, P>;
+    #[inline]
+    fn mul(self, rhs: Matrix<T, N, P>) -> Matrix<T, M, P> {
+        Matrix::from_fn(|i, j| {
+            sum_of_products(N, |k| self.0[i][k].clone(), |k| rhs.0[k][j].clone())
+        })
+    }
+}
+
+impl<T: Semiring, const M: usize, const N: usize> Mul<VecN<T, N>> for Matrix<T, M, N> {
+    type Output = VecN<T, M>;
+    #[inline]
+    fn mul(self, rhs: VecN<T, N>) -> VecN<T, M> {
+        VecN(array::from_fn(|i| {
+            sum_of_products(N, |k| self.0[i][k].clone(), |k| rhs.0[k].clone())
+        }))
+    }
+}
+
+//
+//Identities
+//
+
+impl<T: Zero + AddAssign, const M: usize, const N: usize> Zero for Matrix<T, M, N> {
+    #[inline]
+    fn zero() -> Self {
+        Matrix::from_fn(|_, _| T::zero())
+    }
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.0.iter().flat_map(|row| row.iter()).all(T::is_zero)
+    }
+}
+
+impl<T: Semiring + One + PartialEq, const N: usize> One for Matrix<T, N, N> {
+    #[inline]
+    fn one() -> Self {
+        Matrix::identity()
+    }
+    #[inline]
+    fn is_one(&self) -> bool {
+        (0..N).all(|i| {
+            (0..N).all(|j| (i == j && self.0[i][j].is_one()) || (i != j && self.0[i][j].is_zero()))
+        })
+    }
+}
+
+//
+//Algebraic properties
+//
+//Matrix multiplication is never commutative for N > 1 and has zero divisors, so only the
+//associativity and distributivity of the entries carry over
+//
+
+impl<T: AddAssociative, const M: usize, const N: usize> AddAssociative for Matrix<T, M, N> {}
+impl<T: AddCommutative, const M: usize, const N: usize> AddCommutative for Matrix<T, M, N> {}
+impl<T: Distributive, const M: usize, const N: usize> Distributive<T> for Matrix<T, M, N> {}
+impl<T: Distributive, const N: usize> Distributive for Matrix<T, N, N> {}
+impl<T: MulAssociative + AddAssociative, const N: usize> MulAssociative for Matrix<T, N, N> {}
+
+#[cfg(feature = "std")]
+mod dynamic {
+    use {
+        super::{sum_of_products, Matrix, MatrixLike},
+        crate::{algebra::*, linear::VecN},
+        core::ops::{Index, IndexMut},
+    };
+
+    ///
+    ///A matrix with entries from the ring `T` and dimensions chosen at runtime
+    ///
+    ///The entries are stored in a single [Vec] in row-major order. Every operation between two
+    ///matrices panics if their dimensions are incompatible.
+    ///
+    #[derive(Clone, PartialEq, Eq, Hash, Debug)]
+    pub struct DynMatrix<T> {
+        rows: usize,
+        cols: usize,
+        data: Vec<T>,
+    }
+
+    impl<T> DynMatrix<T> {
+        ///
+        ///Constructs a matrix from its entries in row-major order
+        ///
+        ///Panics if there are not exactly `rows*cols` entries
+        ///
+        pub fn new(rows: usize, cols: usize, data: Vec<T>) -> Self {
+            if data.len() != rows * cols {
+                panic!(
+                    "Attempted to construct a {}x{} matrix from {} entries",
+                    rows,
+                    cols,
+                    data.len()
+                )
+            }
+            DynMatrix { rows, cols, data }
+        }
+
+        ///Constructs a matrix by computing each entry from its row and column
+        pub fn from_fn(rows: usize, cols: usize, mut f: impl FnMut(usize, usize) -> T) -> Self {
+            let data = (0..rows)
+                .flat_map(|i| (0..cols).map(move |j| (i, j)))
+                .map(|(i, j)| f(i, j))
+                .collect();
+            DynMatrix { rows, cols, data }
+        }
+
+        ///The entries of this matrix in row-major order
+        #[inline]
+        pub fn data(&self) -> &[T] {
+            &self.data
+        }
+
+        ///Unwraps this matrix into its entries in row-major order
+        #[inline]
+        pub fn into_data(self) -> Vec<T> {
+            self.data
+        }
+
+        ///The `i`th row of this matrix
+        #[inline]
+        pub fn row(&self, i: usize) -> &[T] {
+            &self.data[i * self.cols..(i + 1) * self.cols]
+        }
+    }
+
+    impl<T: Zero> DynMatrix<T> {
+        ///The `rows`x`cols` zero matrix
+        #[inline]
+        pub fn zeros(rows: usize, cols: usize) -> Self {
+            DynMatrix::from_fn(rows, cols, |_, _| T::zero())
+        }
+    }
+
+    impl<T: Zero + One> DynMatrix<T> {
+        ///The `n`x`n` identity matrix
+        #[inline]
+        pub fn identity(n: usize) -> Self {
+            DynMatrix::from_fn(n, n, |i, j| if i == j { T::one() } else { T::zero() })
+        }
+    }
+
+    impl<T: Clone> DynMatrix<T> {
+        ///Swaps the rows and columns of this matrix
+        #[inline]
+        pub fn transpose(&self) -> Self {
+            DynMatrix::from_fn(self.cols, self.rows, |i, j| self[(j, i)].clone())
+        }
+    }
+
+    impl<T: Semiring> DynMatrix<T> {
+        ///
+        ///The sum of the entries along the main diagonal
+        ///
+        ///Panics if this matrix is not square
+        ///
+        pub fn trace(&self) -> T {
+            if !self.is_square() {
+                panic!(
+                    "Attempted to take the trace of a {}x{} matrix",
+                    self.rows, self.cols
+                )
+            }
+            (0..self.rows).fold(T::zero(), |acc, i| acc + self[(i, i)].clone())
+        }
+    }
+
+    impl<T> MatrixLike for DynMatrix<T> {
+        type Scalar = T;
+
+        #[inline]
+        fn rows(&self) -> usize {
+            self.rows
+        }
+        #[inline]
+        fn cols(&self) -> usize {
+            self.cols
+        }
+        #[inline]
+        fn entry(&self, i: usize, j: usize) -> &T {
+            &self[(i, j)]
+        }
+        #[inline]
+        fn entry_mut(&mut self, i: usize, j: usize) -> &mut T {
+            &mut self[(i, j)]
+        }
+        fn swap_rows(&mut self, i: usize, j: usize) {
+            if i != j {
+                let (i, j) = (i.min(j), i.max(j));
+                let (top, bottom) = self.data.split_at_mut(j * self.cols);
+                top[i * self.cols..(i + 1) * self.cols].swap_with_slice(&mut bottom[..self.cols]);
+            }
+        }
+    }
+
+    impl<T, const M: usize, const N: usize> From<Matrix<T, M, N>> for DynMatrix<T> {
+        #[inline]
+        fn from(m: Matrix<T, M, N>) -> Self {
+            DynMatrix {
+                rows: M,
+                cols: N,
+                data: IntoIterator::into_iter(m.0)
+                    .flat_map(IntoIterator::into_iter)
+                    .collect(),
+            }
+        }
+    }
+
+    impl<T> Index<(usize, usize)> for DynMatrix<T> {
+        type Output = T;
+        #[inline]
+        fn index(&self, (i, j): (usize, usize)) -> &T {
+            if i >= self.rows || j >= self.cols {
+                panic!(
+                    "Attempted to index entry ({}, {}) of a {}x{} matrix",
+                    i, j, self.rows, self.cols
+                )
+            }
+            &self.data[i * self.cols + j]
+        }
+    }
+
+    impl<T> IndexMut<(usize, usize)> for DynMatrix<T> {
+        #[inline]
+        fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut T {
+            if i >= self.rows || j >= self.cols {
+                panic!(
+                    "Attempted to index entry ({}, {}) of a {}x{} matrix",
+                    i, j, self.rows, self.cols
+                )
+            }
+            &mut self.data[i * self.cols + j]
+        }
+    }
+
+    impl<T> DynMatrix<T> {
+        #[inline]
+        fn assert_same_dims(&self, rhs: &Self, op: &str) {
+            if self.rows != rhs.rows || self.cols != rhs.cols {
+                panic!(
+                    "Attempted to {} a {}x{} matrix and a {}x{} matrix",
+                    op, self.rows, self.cols, rhs.rows, rhs.cols
+                )
+            }
+        }
+    }
+
+    //
+    //Unary operations
+    //
+
+    impl<T: Neg<Output = T>> Neg for DynMatrix<T> {
+        type Output = Self;
+        #[inline]
+        fn neg(self) -> Self {
+            DynMatrix {
+                rows: self.rows,
+                cols: self.cols,
+                data: self.data.into_iter().map(T::neg).collect(),
+            }
+        }
+    }
+
+    //
+    //Binary operations
+    //
+
+    impl<T: AddAssign> AddAssign for DynMatrix<T> {
+        #[inline]
+        fn add_assign(&mut self, rhs: Self) {
+            self.assert_same_dims(&rhs, "add");
+            for (x, y) in self.data.iter_mut().zip(rhs.data) {
+                *x += y;
+            }
+        }
+    }
+
+    impl<T: SubAssign> SubAssign for DynMatrix<T> {
+        #[inline]
+        fn sub_assign(&mut self, rhs: Self) {
+            self.assert_same_dims(&rhs, "subtract");
+            for (x, y) in self.data.iter_mut().zip(rhs.data) {
+                *x -= y;
+            }
+        }
+    }
+
+    impl<T: Clone + MulAssign> MulAssign<T> for DynMatrix<T> {
+        #[inline]
+        fn mul_assign(&mut self, rhs: T) {
+            for x in self.data.iter_mut() {
+                *x *= rhs.clone();
+            }
+        }
+    }
+
+    impl<T: Clone + DivAssign> DivAssign<T> for DynMatrix<T> {
+        #[inline]
+        fn div_assign(&mut self, rhs: T) {
+            for x in self.data.iter_mut() {
+                *x /= rhs.clone();
+            }
+        }
+    }
+
+    impl<T: Semiring> MulAssign for DynMatrix<T> {
+        #[inline]
+        fn mul_assign(&mut self, rhs: Self) {
+            *self = self.clone() * rhs;
+        }
+    }
+
+    impl<T: AddAssign> Add for DynMatrix<T> {
+        type Output = Self;
+        #[inline]
+        fn add(mut self, rhs: Self) -> Self {
+            self += rhs;
+            self
+        }
+    }
+
+    impl<T: SubAssign> Sub for DynMatrix<T> {
+        type Output = Self;
+        #[inline]
+        fn sub(mut self, rhs: Self) -> Self {
+            self -= rhs;
+            self
+        }
+    }
+
+    impl<T: Clone + MulAssign> Mul<T> for DynMatrix<T> {
+        type Output = Self;
+        #[inline]
+        fn mul(mut self, rhs: T) -> Self {
+            self *= rhs;
+            self
+        }
+    }
+
+    impl<T: Clone + DivAssign> Div<T> for DynMatrix<T> {
+        type Output = Self;
+        #[inline]
+        fn div(mut self, rhs: T) -> Self {
+            self /= rhs;
+            self
+        }
+    }
+
+    impl<T: Semiring> Mul for DynMatrix<T> {
+        type Output = Self;
+        fn mul(self, rhs: Self) -> Self {
+            if self.cols != rhs.rows {
+                panic!(
+                    "Attempted to multiply a {}x{} matrix by a {}x{} matrix",
+                    self.rows, self.cols, rhs.rows, rhs.cols
+                )
+            }
+            DynMatrix::from_fn(self.rows, rhs.cols, |i, j| {
+                sum_of_products(self.cols, |k| self[(i, k)].clone(), |k| rhs[(k, j)].clone())
+            })
+        }
+    }
+
+    impl<T: Semiring, const N: usize> Mul<VecN<T, N>> for DynMatrix<T> {
+        type Output = Vec<T>;
+        fn mul(self, rhs: VecN<T, N>) -> Vec<T> {
+            if self.cols != N {
+                panic!(
+                    "Attempted to multiply a {}x{} matrix by a vector of length {}",
+                    self.rows, self.cols, N
+                )
+            }
+            (0..self.rows)
+                .map(|i| sum_of_products(N, |k| self[(i, k)].clone(), |k| rhs[k].clone()))
+                .collect()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        algebra::*,
+        linear::{Matrix, MatrixLike, VecN},
+    };
+
+    fn assert_unital_ring<R: UnitalRing>(_: &R) {}
+    fn assert_algebra<K: Field, A: AssociativeAlgebra<K> + UnitalAlgebra<K>>(_: &A) {}
+    fn assert_module<K: UnitalRing, M: RingModule<K>>(_: &M) {}
+
+    #[test]
+    fn multiplication() {
+        let a = Matrix([[1, 2, 3], [4, 5, 6]]);
+        let b = Matrix([[7, 8], [9, 10], [11, 12]]);
+        assert_eq!(a * b, Matrix([[58, 64], [139, 154]]));
+        assert_eq!(b * a, Matrix([[39, 54, 69], [49, 68, 87], [59, 82, 105]]));
+        assert_eq!(a * VecN([1, 0, -1]), VecN([-2, -2]));
+        assert_eq!(a.transpose(), Matrix([[1, 4], [2, 5], [3, 6]]));
+        assert_eq!(a.col(1), VecN([2, 5]));
+        assert_eq!(a.rows(), 2);
+        assert_eq!(a[(1, 2)], 6);
+    }
+
+    #[test]
+    fn ring() {
+        let fib = Matrix([[1i64, 1], [1, 0]]);
+        assert_unital_ring(&fib);
+        assert_eq!(fib.pow_n(10u32), Matrix([[89, 55], [55, 34]]));
+        assert!(Matrix::<i32, 3, 3>::identity().is_one());
+        assert!(Matrix::<i32, 2, 3>::zero().is_zero());
+        assert_eq!(Matrix([[1, 2], [3, 4]]).trace(), 5);
+
+        let r = Matrix([
+            [Rational::new(1, 2), Rational::from(0)],
+            [Rational::from(1), Rational::from(2)],
+        ]);
+        assert_algebra(&r);
+        assert_module(&Matrix([[1, 2, 3]]));
+        assert_eq!(r / Rational::from(2) * Rational::from(2), r);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn dynamic() {
+        use crate::linear::DynMatrix;
+
+        let a = DynMatrix::new(2, 3, vec![1, 2, 3, 4, 5, 6]);
+        let b = DynMatrix::from(Matrix([[7, 8], [9, 10], [11, 12]]));
+        assert_eq!(
+            a.clone() * b.clone(),
+            DynMatrix::from(Matrix([[58, 64], [139, 154]]))
+        );
+        assert_eq!(a.transpose().row(2), &[3, 6]);
+        assert_eq!(a.clone() * VecN([1, 0, -1]), vec![-2, -2]);
+        assert_eq!(
+            DynMatrix::<i32>::identity(2) * 3,
+            DynMatrix::new(2, 2, vec![3, 0, 0, 3])
+        );
+
+        let mut c = b.clone();
+        c.swap_rows(0, 2);
+        assert_eq!(c.row(0), &[11, 12]);
+        assert_eq!(c.row(2), &[7, 8]);
+        assert_eq!(
+            c - b + DynMatrix::zeros(3, 2),
+            DynMatrix::new(3, 2, vec![4, 4, 0, 0, -4, -4])
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    #[should_panic]
+    fn mismatched_dimensions() {
+        use crate::linear::DynMatrix;
+        let _ = DynMatrix::<i32>::zeros(2, 3) + DynMatrix::zeros(3, 2);
+    }
+}
@@ -1,7 +1,355 @@
-pub trait CartesianProduct {
-    type BaseSpace;
+//!
+//!Vectors with a fixed number of components
+//!
 
-    fn dim(&self) -> usize;
-    fn nth(&self) -> Option<&Self::BaseSpace>;
-    fn nth_mut(&self) -> Option<&mut Self::BaseSpace>;
+use {
+    crate::{
+        algebra::*,
+        analysis::{ComplexRing, InnerProductSpace},
+        collection::{self, GenerateError, StaticLenArray},
+    },
+    core::{
+        array,
+        ops::{Index, IndexMut},
+    },
+};
+
+///
+///A vector of `N` components from the ring `T`
+///
+///Addition and scalar multiplication are done component-wise, making this the free
+///`T`-[module](RingModule) of rank `N`, and so a [vector space](VectorSpace) whenever `T` is a [Field].
+///Furthermore, when `T` is a subset of the complex numbers, the standard dot product
+///`⟨x,y⟩ = x₁*̅y̅₁ + ... xₙ*̅y̅ₙ` makes this an [inner product space](InnerProductSpace).
+///
+///This also implements the [array](crate::collection::Array) traits, so for instance, vectors of
+///[reals](crate::analysis::Real) are also [RealArrays](crate::collection::RealArray) and can be
+///measured with the [EuclideanMetric](crate::collection::EuclideanMetric).
+///
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct VecN<T, const N: usize>(pub [T; N]);
+
+impl<T, const N: usize> VecN<T, N> {
+    ///Constructs a vector from its components
+    #[inline]
+    pub fn new(components: [T; N]) -> Self {
+        VecN(components)
+    }
+
+    ///The number of components of this vector, ie, `N`
+    #[inline]
+    pub fn dim(&self) -> usize {
+        N
+    }
+
+    ///Unwraps this vector into its components
+    #[inline]
+    pub fn into_array(self) -> [T; N] {
+        self.0
+    }
+}
+
+impl<T: Zero + One, const N: usize> VecN<T, N> {
+    ///
+    ///The `i`th standard basis vector, ie, the vector with `1` in component `i` and zeros elsewhere
+    ///
+    ///Panics if `i >= N`
+    ///
+    pub fn basis(i: usize) -> Self {
+        if i >= N {
+            panic!(
+                "Attempted to construct basis vector {} of a {}-dimensional space",
+                i, N
+            )
+        }
+        VecN(array::from_fn(
+            |j| if i == j { T::one() } else { T::zero() },
+        ))
+    }
+}
+
+impl<T: Semiring, const N: usize> VecN<T, N> {
+    ///
+    ///The sum of the products of each pair of components, ie, `x₁*y₁ + ... xₙ*yₙ`
+    ///
+    ///Unlike the [inner product](InnerProductSpace::inner_product), this does not conjugate
+    ///`rhs` and is available over any [Semiring]
+    ///
+    #[inline]
+    pub fn dot(self, rhs: Self) -> T {
+        IntoIterator::into_iter(self.0)
+            .zip(IntoIterator::into_iter(rhs.0))
+            .fold(T::zero(), |acc, (x, y)| acc + x * y)
+    }
+}
+
+impl<T: Ring> VecN<T, 3> {
+    ///The cross product of two 3-dimensional vectors
+    #[inline]
+    pub fn cross(self, rhs: Self) -> Self {
+        let [x1, y1, z1] = self.0;
+        let [x2, y2, z2] = rhs.0;
+        VecN([
+            y1.clone() * z2.clone() - z1.clone() * y2.clone(),
+            z1 * x2.clone() - x1.clone() * z2,
+            x1 * y2 - y1 * x2,
+        ])
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for VecN<T, N> {
+    #[inline]
+    fn from(components: [T; N]) -> Self {
+        VecN(components)
+    }
+}
+
+impl<T, const N: usize> From<VecN<T, N>> for [T; N] {
+    #[inline]
+    fn from(v: VecN<T, N>) -> Self {
+        v.0
+    }
+}
+
+impl<T, const N: usize> Index<usize> for VecN<T, N> {
+    type Output = T;
+    #[inline]
+    fn index(&self, i: usize) -> &T {
+        &self.0[i]
+    }
+}
+
+impl<T, const N: usize> IndexMut<usize> for VecN<T, N> {
+    #[inline]
+    fn index_mut(&mut self, i: usize) -> &mut T {
+        &mut self.0[i]
+    }
+}
+
+//
+//Unary operations
+//
+
+impl<T: Neg<Output = T>, const N: usize> Neg for VecN<T, N> {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        VecN(self.0.map(T::neg))
+    }
+}
+
+//
+//Binary operations
+//
+
+impl<T: AddAssign, const N: usize> AddAssign for VecN<T, N> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        for (x, y) in self.0.iter_mut().zip(IntoIterator::into_iter(rhs.0)) {
+            *x += y;
+        }
+    }
+}
+
+impl<T: SubAssign, const N: usize> SubAssign for VecN<T, N> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        for (x, y) in self.0.iter_mut().zip(IntoIterator::into_iter(rhs.0)) {
+            *x -= y;
+        }
+    }
+}
+
+impl<T: Clone + MulAssign, const N: usize> MulAssign<T> for VecN<T, N> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: T) {
+        for x in self.0.iter_mut() {
+            *x *= rhs.clone();
+        }
+    }
+}
+
+impl<T: Clone + DivAssign, const N: usize> DivAssign<T> for VecN<T, N> {
+    #[inline]
+    fn div_assign(&mut self, rhs: T) {
+        for x in self.0.iter_mut() {
+            *x /= rhs.clone();
+        }
+    }
+}
+
+impl<T: AddAssign, const N: usize> Add for VecN<T, N> {
+    type Output = Self;
+    #[inline]
+    fn add(mut self, rhs: Self) -> Self {
+        self += rhs;
+        self
+    }
+}
+
+impl<T: SubAssign, const N: usize> Sub for VecN<T, N> {
+    type Output = Self;
+    #[inline]
+    fn sub(mut self, rhs: Self) -> Self {
+        self -= rhs;
+        self
+    }
+}
+
+impl<T: Clone + MulAssign, const N: usize> Mul<T> for VecN<T, N> {
+    type Output = Self;
+    #[inline]
+    fn mul(mut self, rhs: T) -> Self {
+        self *= rhs;
+        self
+    }
+}
+
+impl<T: Clone + DivAssign, const N: usize> Div<T> for VecN<T, N> {
+    type Output = Self;
+    #[inline]
+    fn div(mut self, rhs: T) -> Self {
+        self /= rhs;
+        self
+    }
+}
+
+//
+//Identities
+//
+
+impl<T: Zero + AddAssign, const N: usize> Zero for VecN<T, N> {
+    #[inline]
+    fn zero() -> Self {
+        VecN(array::from_fn(|_| T::zero()))
+    }
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.0.iter().all(T::is_zero)
+    }
+}
+
+//
+//Algebraic properties
+//
+
+impl<T: AddAssociative, const N: usize> AddAssociative for VecN<T, N> {}
+impl<T: AddCommutative, const N: usize> AddCommutative for VecN<T, N> {}
+impl<T: Distributive, const N: usize> Distributive<T> for VecN<T, N> {}
+
+impl<T: ComplexRing, const N: usize> InnerProductSpace<T> for VecN<T, N> {
+    #[inline]
+    fn inner_product(self, rhs: Self) -> T {
+        IntoIterator::into_iter(self.0)
+            .zip(IntoIterator::into_iter(rhs.0))
+            .fold(T::zero(), |acc, (x, y)| acc + x * y.conj())
+    }
+}
+
+//
+//Array traits
+//
+
+impl<T, const N: usize> collection::Array for VecN<T, N> {
+    type Element = T;
+
+    #[inline(always)]
+    fn nth(&self, n: usize) -> Option<&T> {
+        self.0.get(n)
+    }
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        N
+    }
+}
+
+impl<T, const N: usize> collection::ArrayMut for VecN<T, N> {
+    #[inline(always)]
+    fn nth_mut(&mut self, n: usize) -> Option<&mut T> {
+        self.0.get_mut(n)
+    }
+}
+
+impl<T, const N: usize> collection::GenArray for VecN<T, N> {
+    #[inline(always)]
+    fn try_generate(gen: impl Iterator<Item = T>) -> Result<Self, GenerateError> {
+        <Self as StaticLenArray>::try_generate(gen)
+    }
+}
+
+impl<T, const N: usize> StaticLenArray for VecN<T, N> {
+    type Element = T;
+
+    #[inline(always)]
+    fn len() -> usize {
+        N
+    }
+
+    #[inline(always)]
+    fn try_generate(gen: impl Iterator<Item = T>) -> Result<Self, GenerateError> {
+        <[T; N] as StaticLenArray>::try_generate(gen).map(VecN)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::{
+        algebra::*,
+        analysis::{complex::Complex, InnerProductSpace, Metric},
+        collection::{EuclideanMetric, GenArray, RealArray},
+        linear::VecN,
+    };
+
+    fn assert_vector_space<K: Field, V: VectorSpace<K>>(_: &V) {}
+    fn assert_real_array<A: RealArray>(_: &A) {}
+
+    #[test]
+    fn arithmetic() {
+        let x = VecN([1, 2, 3]);
+        let y = VecN([4, 5, 6]);
+
+        assert_eq!(x + y, VecN([5, 7, 9]));
+        assert_eq!(x - y, VecN([-3, -3, -3]));
+        assert_eq!(-x, VecN([-1, -2, -3]));
+        assert_eq!(x * 2, VecN([2, 4, 6]));
+        assert_eq!(x.mul_z(-2), VecN([-2, -4, -6]));
+        assert_eq!(x.dot(y), 32);
+        assert_eq!(x.cross(y), VecN([-3, 6, -3]));
+        assert_eq!(VecN::<i32, 3>::basis(1), VecN([0, 1, 0]));
+        assert!(VecN::<i32, 3>::zero().is_zero());
+
+        let r = VecN([Rational::new(1, 2), Rational::new(3, 4)]);
+        assert_vector_space(&r);
+        assert_eq!(
+            r / Rational::from(2),
+            VecN([Rational::new(1, 4), Rational::new(3, 8)])
+        );
+    }
+
+    #[test]
+    fn inner_product() {
+        let x = VecN([3.0, 4.0]);
+        let y = VecN([-4.0, 3.0]);
+        assert_eq!(x.norm(), 5.0);
+        assert!(x.orthogonal(y));
+        assert_eq!(VecN([1.0, 0.0]).project(x), VecN([3.0, 0.0]));
+
+        let z = VecN([Complex::new(1.0, 1.0), Complex::new(0.0, 2.0)]);
+        assert_eq!(z.inner_product(z), Complex::new(6.0, 0.0));
+        assert_eq!(z.norm_sqrd(), 6.0);
+    }
+
+    #[test]
+    fn arrays() {
+        let x = VecN([0.0, 3.0, 0.0]);
+        let y = VecN([4.0, 0.0, 0.0]);
+        assert_real_array(&x);
+        assert_eq!(EuclideanMetric.distance(&x, &y), 5.0);
+        assert_eq!(
+            VecN::<f64, 3>::generate((1..).map(f64::from)),
+            VecN([1.0, 2.0, 3.0])
+        );
+        assert_eq!(x.component_wise(&y, |a, b| a + b), VecN([4.0, 3.0, 0.0]));
+    }
 }
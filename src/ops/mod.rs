@@ -166,10 +166,56 @@ macro_rules! impl_div {
     };
 }
 
+macro_rules! impl_neg {
+    ($($z:ty)*) => {
+        $(
+            impl Neg for $z {
+                type Output = $z;
+
+                fn neg(self) -> Self::Output {
+                    -self
+                }
+            }
+
+            impl<'a> Neg for &'a $z {
+                type Output = $z;
+
+                fn neg(self) -> Self::Output {
+                    -self
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_inv {
+    ($($z:ty)*) => {
+        $(
+            impl Inv for $z {
+                type Output = $z;
+
+                fn inv(self) -> Self::Output {
+                    1.0 / self
+                }
+            }
+
+            impl<'a> Inv for &'a $z {
+                type Output = $z;
+
+                fn inv(self) -> Self::Output {
+                    1.0 / self
+                }
+            }
+        )*
+    };
+}
+
 impl_add! { usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 f32 f64}
 impl_sub! { usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 f32 f64}
 impl_mul! { usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 f32 f64}
 impl_div! { usize u8 u16 u32 u64 u128 isize i8 i16 i32 i64 i128 f32 f64}
+impl_neg! { isize i8 i16 i32 i64 i128 f32 f64}
+impl_inv! { f32 f64}
 
 pub trait RefAdd = where for<'a> &'a Self: Sized + Add<&'a Self, Output = Self>;
 pub trait RefSub = where for<'a> &'a Self: Sized + Sub<&'a Self, Output = Self>;
@@ -178,3 +224,60 @@ pub trait RefNeg = where for<'a> &'a Self: Sized + Neg<Output = Self>;
 pub trait RefMul = where for<'a> &'a Self: Sized + Mul<&'a Self, Output = Self>;
 pub trait RefDiv = where for<'a> &'a Self: Sized + Div<&'a Self, Output = Self>;
 pub trait RefInv = where for<'a> &'a Self: Sized + Inv<Output = Self>;
+
+#[cfg(test)]
+mod tests {
+    use crate::ops::{self, *};
+
+    #[derive(Clone, Copy, PartialEq, Debug)]
+    struct Wrapper<T>(T);
+
+    impl<T: ops::Add<Output = T>> ops::Add for Wrapper<T> {
+        type Output = Self;
+        fn add(self, rhs: Self) -> Self {
+            Wrapper(self.0.add(rhs.0))
+        }
+    }
+
+    impl<'a, T: Copy + ops::Add<Output = T>> ops::Add<&'a Wrapper<T>> for &'a Wrapper<T> {
+        type Output = Wrapper<T>;
+        fn add(self, rhs: Self) -> Wrapper<T> {
+            Wrapper(self.0.add(rhs.0))
+        }
+    }
+
+    impl<T: ops::Add<Output = T> + Copy> ops::AddAssign for Wrapper<T> {
+        fn add_assign(&mut self, rhs: Self) {
+            self.0 = self.0.add(rhs.0)
+        }
+    }
+
+    impl<T: ops::Neg<Output = T>> ops::Neg for Wrapper<T> {
+        type Output = Self;
+        fn neg(self) -> Self {
+            Wrapper(self.0.neg())
+        }
+    }
+
+    impl<T: ops::Inv<Output = T>> ops::Inv for Wrapper<T> {
+        type Output = Self;
+        fn inv(self) -> Self {
+            Wrapper(self.0.inv())
+        }
+    }
+
+    traitor_ops!([Add, AddAssign, Neg, Inv] Wrapper<T: Copy>);
+
+    #[test]
+    fn forwarding() {
+        let (x, y) = (Wrapper(1.0), Wrapper(2.0));
+        assert_eq!(x + y, Wrapper(3.0));
+        assert_eq!(&x + &y, Wrapper(3.0));
+        assert_eq!(-x, Wrapper(-1.0));
+        assert_eq!(num_traits::Inv::inv(y), Wrapper(0.5));
+
+        let mut z = x;
+        z += y;
+        assert_eq!(z, Wrapper(3.0));
+    }
+}
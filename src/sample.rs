@@ -0,0 +1,485 @@
+//!
+//!Random generation of elements for testing and fuzzing
+//!
+//!The [Sample] trait generates random elements of a type from any source of randomness implementing
+//![Rng], and [Xoshiro256] provides a small, seedable, and dependency-free source to drive it:
+//!```
+//!# use traitor::{algebra::Rational, sample::*};
+//!let (mut rng, mut same) = (Xoshiro256::new(1234), Xoshiro256::new(1234));
+//!
+//!//the same seed always gives the same elements
+//!let x: Rational<i64> = rng.sample_small();
+//!assert_eq!(x, same.sample_small());
+//!
+//!let v: Vec<u8> = rng.sample();
+//!assert!(v.len() <= MAX_SAMPLE_LEN);
+//!```
+//!
+//!Every type the crate implements its structures on can be sampled, and implementing [Sample] for
+//!your own [Ring] or [Field] lets them be fed to the checks in the `laws` module the same way.
+//!
+//!Note that the generators here are meant for reproducible tests and are _not_ cryptographically secure.
+//!
+
+use {
+    crate::{
+        algebra::*,
+        analysis::complex::Complex,
+        linear::{Matrix, VecN},
+    },
+    core::{array, num::Wrapping},
+};
+
+///A source of uniformly random bits
+pub trait Rng {
+    ///A uniformly random 64-bit integer
+    fn next_u64(&mut self) -> u64;
+
+    ///A uniformly random 32-bit integer
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    ///A uniformly random float in `[0, 1)`
+    #[inline]
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
+    }
+
+    ///
+    ///A uniformly random integer in `[0, n)`
+    ///
+    ///Panics if `n` is zero
+    ///
+    fn below(&mut self, n: u64) -> u64 {
+        if n == 0 {
+            panic!("Attempted to sample from an empty range")
+        }
+
+        //Lemire's multiply-and-reject method, which avoids the bias of taking a remainder
+        let mut m = self.next_u64() as u128 * n as u128;
+        if (m as u64) < n {
+            let threshold = n.wrapping_neg() % n;
+            while (m as u64) < threshold {
+                m = self.next_u64() as u128 * n as u128;
+            }
+        }
+        (m >> 64) as u64
+    }
+
+    ///A random element of `T`
+    #[inline]
+    fn sample<T: Sample>(&mut self) -> T {
+        T::sample(self)
+    }
+
+    ///A random element of `T` of a [modest size](Sample::sample_small)
+    #[inline]
+    fn sample_small<T: Sample>(&mut self) -> T {
+        T::sample_small(self)
+    }
+}
+
+impl<R: Rng + ?Sized> Rng for &mut R {
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        (**self).next_u64()
+    }
+}
+
+///
+///The [xoshiro256\*\*](https://prng.di.unimi.it/) pseudo-random number generator
+///
+///This is a fast, general purpose generator with 256 bits of state, seeded from a single `u64` by
+///way of [SplitMix64](https://prng.di.unimi.it/splitmix64.c) so that similar seeds still give
+///unrelated sequences.
+///
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Xoshiro256 {
+    state: [u64; 4],
+}
+
+impl Xoshiro256 {
+    ///Constructs a generator from the given seed
+    pub fn new(seed: u64) -> Self {
+        let mut x = seed;
+        let mut splitmix = || {
+            x = x.wrapping_add(0x9e3779b97f4a7c15);
+            let mut z = x;
+            z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+            z ^ (z >> 31)
+        };
+        Xoshiro256 {
+            state: [splitmix(), splitmix(), splitmix(), splitmix()],
+        }
+    }
+}
+
+impl Rng for Xoshiro256 {
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        let s = &mut self.state;
+        let result = s[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = s[1] << 17;
+
+        s[2] ^= s[0];
+        s[3] ^= s[1];
+        s[1] ^= s[2];
+        s[0] ^= s[3];
+        s[2] ^= t;
+        s[3] = s[3].rotate_left(45);
+
+        result
+    }
+}
+
+///
+///A type whose elements can be generated randomly
+///
+///Implementations should be able to generate any element of the type, or at least a representative
+///spread of them, while [sample_small](Sample::sample_small) is used for anything that needs values
+///small enough to do arithmetic with without overflowing, like when [checking laws](crate::laws).
+///
+pub trait Sample: Sized {
+    ///A random element of this type
+    fn sample<G: Rng + ?Sized>(rng: &mut G) -> Self;
+
+    ///
+    ///A random element of this type of a modest size
+    ///
+    ///For the primitive integers, this is any value with an absolute value less than `2^(BITS/4)`,
+    ///so that the product of any three of them still fits, and for floats, any value in `[-16, 16)`.
+    ///By default, this is the same as [sample](Sample::sample).
+    ///
+    #[inline]
+    fn sample_small<G: Rng + ?Sized>(rng: &mut G) -> Self {
+        Self::sample(rng)
+    }
+}
+
+impl Sample for bool {
+    #[inline]
+    fn sample<G: Rng + ?Sized>(rng: &mut G) -> Self {
+        rng.next_u64() >> 63 == 1
+    }
+}
+
+macro_rules! impl_sample_int {
+    ($($u:ident:$s:ident)*) => {
+        $(
+            impl Sample for $u {
+                #[inline]
+                fn sample<G: Rng + ?Sized>(rng: &mut G) -> Self {
+                    (((rng.next_u64() as u128) << 64) | rng.next_u64() as u128) as $u
+                }
+                #[inline]
+                fn sample_small<G: Rng + ?Sized>(rng: &mut G) -> Self {
+                    rng.below(1 << ($u::BITS / 4)) as $u
+                }
+            }
+
+            impl Sample for $s {
+                #[inline]
+                fn sample<G: Rng + ?Sized>(rng: &mut G) -> Self {
+                    $u::sample(rng) as $s
+                }
+                #[inline]
+                fn sample_small<G: Rng + ?Sized>(rng: &mut G) -> Self {
+                    let bound = 1 << ($u::BITS / 4);
+                    rng.below(2 * bound - 1) as $s - (bound - 1) as $s
+                }
+            }
+        )*
+    };
+}
+impl_sample_int!(u8:i8 u16:i16 u32:i32 u64:i64 u128:i128 usize:isize);
+
+macro_rules! impl_sample_float {
+    ($($f:ident)*) => {
+        $(
+            impl Sample for $f {
+                ///A value in `[-1, 1)` scaled by a power of two in `[2^-16, 2^16]`
+                #[inline]
+                fn sample<G: Rng + ?Sized>(rng: &mut G) -> Self {
+                    let x = rng.next_f64() * 2.0 - 1.0;
+                    let exp = rng.below(33) as i32 - 16;
+                    (x * 2f64.powi(exp)) as $f
+                }
+                #[inline]
+                fn sample_small<G: Rng + ?Sized>(rng: &mut G) -> Self {
+                    (rng.next_f64() * 32.0 - 16.0) as $f
+                }
+            }
+        )*
+    };
+}
+impl_sample_float!(f32 f64);
+
+impl<T: Sample> Sample for Wrapping<T> {
+    #[inline]
+    fn sample<G: Rng + ?Sized>(rng: &mut G) -> Self {
+        Wrapping(T::sample(rng))
+    }
+    #[inline]
+    fn sample_small<G: Rng + ?Sized>(rng: &mut G) -> Self {
+        Wrapping(T::sample_small(rng))
+    }
+}
+
+impl<T: Sample, const N: usize> Sample for [T; N] {
+    #[inline]
+    fn sample<G: Rng + ?Sized>(rng: &mut G) -> Self {
+        array::from_fn(|_| T::sample(rng))
+    }
+    #[inline]
+    fn sample_small<G: Rng + ?Sized>(rng: &mut G) -> Self {
+        array::from_fn(|_| T::sample_small(rng))
+    }
+}
+
+///The largest length of a randomly generated [Vec] or [SmallVec](smallvec::SmallVec)
+pub const MAX_SAMPLE_LEN: usize = 16;
+
+#[cfg(feature = "std")]
+impl<T: Sample> Sample for Vec<T> {
+    ///A vector with a random length up to [MAX_SAMPLE_LEN]
+    #[inline]
+    fn sample<G: Rng + ?Sized>(rng: &mut G) -> Self {
+        let len = rng.below(MAX_SAMPLE_LEN as u64 + 1);
+        (0..len).map(|_| T::sample(rng)).collect()
+    }
+    #[inline]
+    fn sample_small<G: Rng + ?Sized>(rng: &mut G) -> Self {
+        let len = rng.below(MAX_SAMPLE_LEN as u64 + 1);
+        (0..len).map(|_| T::sample_small(rng)).collect()
+    }
+}
+
+impl<T: Sample, const LEN: usize> Sample for smallvec::SmallVec<[T; LEN]> {
+    ///A vector with a random length up to [MAX_SAMPLE_LEN]
+    #[inline]
+    fn sample<G: Rng + ?Sized>(rng: &mut G) -> Self {
+        let len = rng.below(MAX_SAMPLE_LEN as u64 + 1);
+        (0..len).map(|_| T::sample(rng)).collect()
+    }
+    #[inline]
+    fn sample_small<G: Rng + ?Sized>(rng: &mut G) -> Self {
+        let len = rng.below(MAX_SAMPLE_LEN as u64 + 1);
+        (0..len).map(|_| T::sample_small(rng)).collect()
+    }
+}
+
+//
+//The crate's own types
+//
+
+impl<T: GCDDomain + Sample> Sample for Rational<T> {
+    ///A fraction of two random elements, resampling the denominator until it is nonzero
+    #[inline]
+    fn sample<G: Rng + ?Sized>(rng: &mut G) -> Self {
+        let n = T::sample(rng);
+        let d = loop {
+            let d = T::sample(rng);
+            if !d.is_zero() {
+                break d;
+            }
+        };
+        Rational::new(n, d)
+    }
+    #[inline]
+    fn sample_small<G: Rng + ?Sized>(rng: &mut G) -> Self {
+        let n = T::sample_small(rng);
+        let d = loop {
+            let d = T::sample_small(rng);
+            if !d.is_zero() {
+                break d;
+            }
+        };
+        Rational::new(n, d)
+    }
+}
+
+impl<const N: u64> Sample for Zn<N> {
+    #[inline]
+    fn sample<G: Rng + ?Sized>(rng: &mut G) -> Self {
+        Zn::new(rng.below(N))
+    }
+}
+
+impl<R: Sample> Sample for Complex<R> {
+    #[inline]
+    fn sample<G: Rng + ?Sized>(rng: &mut G) -> Self {
+        let re = R::sample(rng);
+        Complex {
+            re,
+            im: R::sample(rng),
+        }
+    }
+    #[inline]
+    fn sample_small<G: Rng + ?Sized>(rng: &mut G) -> Self {
+        let re = R::sample_small(rng);
+        Complex {
+            re,
+            im: R::sample_small(rng),
+        }
+    }
+}
+
+impl<T: Sample, const N: usize> Sample for VecN<T, N> {
+    #[inline]
+    fn sample<G: Rng + ?Sized>(rng: &mut G) -> Self {
+        VecN(Sample::sample(rng))
+    }
+    #[inline]
+    fn sample_small<G: Rng + ?Sized>(rng: &mut G) -> Self {
+        VecN(Sample::sample_small(rng))
+    }
+}
+
+impl<T: Sample, const M: usize, const N: usize> Sample for Matrix<T, M, N> {
+    #[inline]
+    fn sample<G: Rng + ?Sized>(rng: &mut G) -> Self {
+        Matrix(Sample::sample(rng))
+    }
+    #[inline]
+    fn sample_small<G: Rng + ?Sized>(rng: &mut G) -> Self {
+        Matrix(Sample::sample_small(rng))
+    }
+}
+
+#[cfg(feature = "std")]
+impl Sample for BigNat {
+    ///A natural number of up to four limbs
+    #[inline]
+    fn sample<G: Rng + ?Sized>(rng: &mut G) -> Self {
+        let len = rng.below(5);
+        BigNat::from_limbs((0..len).map(|_| rng.next_u64()).collect())
+    }
+    ///A natural number of at most one limb
+    #[inline]
+    fn sample_small<G: Rng + ?Sized>(rng: &mut G) -> Self {
+        BigNat::from_limbs(vec![rng.next_u64()])
+    }
+}
+
+#[cfg(feature = "std")]
+impl Sample for BigInt {
+    #[inline]
+    fn sample<G: Rng + ?Sized>(rng: &mut G) -> Self {
+        let negative = bool::sample(rng);
+        BigInt::from_sign_magnitude(negative, BigNat::sample(rng))
+    }
+    #[inline]
+    fn sample_small<G: Rng + ?Sized>(rng: &mut G) -> Self {
+        let negative = bool::sample(rng);
+        BigInt::from_sign_magnitude(negative, BigNat::sample_small(rng))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R: Ring + Sample> Sample for Polynomial<R> {
+    ///A polynomial of degree less than four
+    #[inline]
+    fn sample<G: Rng + ?Sized>(rng: &mut G) -> Self {
+        let len = rng.below(5);
+        Polynomial::new((0..len).map(|_| R::sample(rng)).collect())
+    }
+    #[inline]
+    fn sample_small<G: Rng + ?Sized>(rng: &mut G) -> Self {
+        let len = rng.below(5);
+        Polynomial::new((0..len).map(|_| R::sample_small(rng)).collect())
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use {
+        crate::{laws::*, sample::*},
+        smallvec::SmallVec,
+    };
+
+    #[test]
+    fn generator() {
+        let mut a = Xoshiro256::new(42);
+        let mut b = Xoshiro256::new(42);
+        let mut c = Xoshiro256::new(43);
+        let xs: Vec<u64> = (0..8).map(|_| a.next_u64()).collect();
+        assert!(xs.iter().all(|&x| x == b.next_u64()));
+        assert!(xs.iter().any(|&x| x != c.next_u64()));
+
+        //a rough check that every bucket gets used about evenly
+        let mut counts = [0; 6];
+        for _ in 0..6000 {
+            counts[a.below(6) as usize] += 1;
+        }
+        assert!(
+            counts.iter().all(|&n| (800..1200).contains(&n)),
+            "{:?}",
+            counts
+        );
+
+        assert!((0..1000)
+            .map(|_| a.next_f64())
+            .all(|x| (0.0..1.0).contains(&x)));
+    }
+
+    #[test]
+    fn primitives() {
+        let mut rng = Xoshiro256::new(7);
+        for _ in 0..1000 {
+            assert!(rng.sample_small::<i8>().abs() < 4);
+            assert!(rng.sample_small::<u32>() < 256);
+            assert!((-16.0..16.0).contains(&rng.sample_small::<f64>()));
+            assert!(rng.sample::<f32>().abs() <= 65536.0);
+            assert!(rng.sample::<Vec<u8>>().len() <= MAX_SAMPLE_LEN);
+            assert!(rng.sample::<SmallVec<[u8; 4]>>().len() <= MAX_SAMPLE_LEN);
+        }
+        assert!((0..100)
+            .map(|_| rng.sample::<i64>())
+            .any(|x| x.abs() > 1 << 40));
+
+        let samples: Vec<i32> = (0..8).map(|_| rng.sample_small()).collect();
+        check_commutative_ring_laws(&samples).unwrap();
+        let samples: Vec<[u16; 3]> = (0..8).map(|_| rng.sample_small()).collect();
+        assert!(samples.iter().flatten().all(|&x| x < 16));
+        let samples: Vec<core::num::Wrapping<i64>> = (0..8).map(|_| rng.sample()).collect();
+        check_commutative_ring_laws(&samples).unwrap();
+    }
+
+    #[test]
+    fn structures() {
+        let mut rng = Xoshiro256::new(99);
+
+        let samples: Vec<Rational<BigInt>> = (0..8).map(|_| rng.sample_small()).collect();
+        check_ordered_field_laws(&samples).unwrap();
+        let samples: Vec<Zn<13>> = (0..8).map(|_| rng.sample()).collect();
+        check_field_laws(&samples).unwrap();
+        let samples: Vec<BigInt> = (0..8).map(|_| rng.sample()).collect();
+        check_commutative_ring_laws(&samples).unwrap();
+        let samples: Vec<Polynomial<i64>> = (0..6).map(|_| rng.sample_small()).collect();
+        check_commutative_ring_laws(&samples).unwrap();
+        let samples: Vec<Matrix<Zn<7>, 2, 2>> = (0..6).map(|_| rng.sample()).collect();
+        check_unital_ring_laws(&samples).unwrap();
+    }
+
+    //a user-defined field can hook into the checks by implementing Sample itself
+    #[derive(Clone, Copy, PartialEq, Debug, crate::algebra::Field)]
+    #[traitor(component_wise)]
+    struct Real(f64);
+
+    impl Sample for Real {
+        fn sample<G: Rng + ?Sized>(rng: &mut G) -> Self {
+            Real(rng.sample_small())
+        }
+    }
+
+    #[test]
+    fn user_types() {
+        let mut rng = Xoshiro256::new(5);
+        let samples: Vec<Real> = (0..6).map(|_| rng.sample()).collect();
+        assert!(samples.iter().all(|x| x.0.abs() < 16.0));
+        assert_ne!(samples[0], samples[1]);
+    }
+}